@@ -19,7 +19,7 @@ effects: Array<AnyEffect> };
  * A wrapper for any effect that can be rendered.
  * Used for serialization and deserialization.
  */
-export type AnyEffect = { "type": "AdditiveCompositor" } & AdditiveCompositorEffect | { "type": "AlphaCompositor" } & AlphaCompositorEffect | { "type": "Stripe" } & StripeEffect | { "type": "MusicVisualizer" } & MusicVisualizerEffect | { "type": "Rotate" } & RotateEffect | { "type": "FlashingColor" } & FlashingColorEffect | { "type": "SolidColor" } & SolidColorEffect | { "type": "WebsocketInput" } & WebsocketInputEffect | { "type": "NodeEditorEffect" } & NodeEditorEffect;
+export type AnyEffect = { "type": "AdditiveCompositor" } & AdditiveCompositorEffect | { "type": "AlphaCompositor" } & AlphaCompositorEffect | { "type": "Stripe" } & StripeEffect | { "type": "MusicVisualizer" } & MusicVisualizerEffect | { "type": "Rotate" } & RotateEffect | { "type": "FlashingColor" } & FlashingColorEffect | { "type": "SolidColor" } & SolidColorEffect | { "type": "White" } & WhiteEffect | { "type": "ExpressionColor" } & ExpressionColorEffect | { "type": "LinearGradient" } & LinearGradientEffect | { "type": "RadialGradient" } & RadialGradientEffect | { "type": "PlaneWave" } & PlaneWaveEffect | { "type": "Fire" } & FireEffect | { "type": "Twinkle" } & TwinkleEffect | { "type": "Comet" } & CometEffect | { "type": "Image" } & ImageEffect | { "type": "Clock" } & ClockEffect | { "type": "WebsocketInput" } & WebsocketInputEffect | { "type": "NodeEditorEffect" } & NodeEditorEffect;
 
 /**
  * Generated binding.
@@ -39,62 +39,83 @@ export type StripeEffect = {
 /**
  * The width of each stripe in pixels.
  */
-stripe_width: number, 
+stripe_width: AnyExpression, 
 /**
- * The colors of the stripes.
+ * The colors of the stripes: one stripe for every stop, sampled evenly along the gradient.
  */
-stripe_colors: Array<PixelColor> };
+stripe_colors: Gradient };
 
 /**
  * Generated binding.
- * A pixel is a single unit of color data with an alpha value.
+ * Tagged with "type".
+ * A wrapper for any expression.
+ * Used for serialization and deserialization.
+ * Expressions can also be deserialized from their text form, like `"time * 84"`, or from a plain number.
  */
-export type PixelColor = { r: number, g: number, b: number, alpha: number };
+export type AnyExpression = { "type": "Number" } & LiteralExpression | { "type": "CurrentTime" } & CurrentTimeExpression | { "type": "FrameCount" } & FrameCountExpression | { "type": "PixelIndex" } & PixelIndexExpression | { "type": "PixelPosition" } & PixelPositionExpression | { "type": "PixelX" } & PixelXExpression | { "type": "PixelY" } & PixelYExpression | { "type": "Variable" } & VariableExpression | { "type": "Audio" } & AudioExpression | { "type": "Add" } & AddExpression | { "type": "Subtract" } & SubtractExpression | { "type": "Multiply" } & MultiplyExpression | { "type": "Divide" } & DivideExpression | { "type": "Round" } & RoundExpression | { "type": "Floor" } & FloorExpression | { "type": "Ceil" } & CeilExpression | { "type": "Sin" } & SinExpression | { "type": "Cos" } & CosExpression | { "type": "Abs" } & AbsExpression | { "type": "Min" } & MinExpression | { "type": "Max" } & MaxExpression | { "type": "Clamp" } & ClampExpression | { "type": "Modulo" } & ModuloExpression | { "type": "Power" } & PowerExpression | { "type": "Lerp" } & LerpExpression | { "type": "Smoothstep" } & SmoothstepExpression | { "type": "Ease" } & EaseExpression | { "type": "Keyframes" } & KeyframesExpression | { "type": "Select" } & SelectExpression | { "type": "Random" } & RandomExpression | { "type": "Noise" } & NoiseExpression;
 
 /**
  * Generated binding.
- * The music visualizer effect runs a TCP socket server that listens for
- * audio data from the music visualizer client. Then, it renders the audio
- * data as a visualizer.
+ * A constant value.
  */
-export type MusicVisualizerEffect = { 
+export type LiteralExpression = { value: number };
+
 /**
- * The UDP listener that listens for audio data from the music visualizer client.
+ * Generated binding.
+ * Gets the current time in seconds.
  */
-port: number };
+export type CurrentTimeExpression = {  };
 
 /**
  * Generated binding.
+ * Gets the number of frames rendered so far.
  */
-export type RotateEffect = { 
+export type FrameCountExpression = {  };
+
 /**
- * The effect to rotate
+ * Generated binding.
+ * Gets the index of the pixel being computed.
  */
-effect: AnyEffect, 
+export type PixelIndexExpression = {  };
+
 /**
- * The number of pixels to rotate the frame by. If negative, it rotates to the left.
+ * Generated binding.
+ * Gets the position of the pixel being computed along the strip, from 0 to 1.
  */
-rotation: AnyExpression };
+export type PixelPositionExpression = {  };
 
 /**
  * Generated binding.
- * Tagged with "type".
- * A wrapper for any expression.
- * Used for serialization and deserialization.
+ * Gets the x coordinate of the pixel being computed in the room, in meters.
  */
-export type AnyExpression = { "type": "Number" } & LiteralExpression | { "type": "CurrentTime" } & CurrentTimeExpression | { "type": "Add" } & AddExpression | { "type": "Subtract" } & SubtractExpression | { "type": "Multiply" } & MultiplyExpression | { "type": "Divide" } & DivideExpression | { "type": "Round" } & RoundExpression | { "type": "Floor" } & FloorExpression | { "type": "Ceil" } & CeilExpression;
+export type PixelXExpression = {  };
 
 /**
  * Generated binding.
- * A constant value.
+ * Gets the y coordinate of the pixel being computed in the room, in meters.
  */
-export type LiteralExpression = { value: number };
+export type PixelYExpression = {  };
 
 /**
  * Generated binding.
- * Gets the current time in seconds.
+ * Gets the value of a variable set at runtime, or 0 if it isn't set.
+ * Written as `$name` in the text form of expressions.
  */
-export type CurrentTimeExpression = {  };
+export type VariableExpression = { name: string };
+
+/**
+ * Generated binding.
+ * Gets a value from the latest audio data published by the music visualizer.
+ * Values fall to 0 when no music visualizer is running.
+ */
+export type AudioExpression = { value: AudioValue };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * A value computed from the latest audio data, from 0 to 1.
+ */
+export type AudioValue = { "type": "Level" } | { "type": "Bass" } | { "type": "Mid" } | { "type": "Treble" } | { "type": "Beat" };
 
 /**
  * Generated binding.
@@ -131,6 +152,242 @@ export type FloorExpression = { number: AnyExpression };
  */
 export type CeilExpression = { number: AnyExpression };
 
+/**
+ * Generated binding.
+ * The sine of a number in radians.
+ */
+export type SinExpression = { number: AnyExpression };
+
+/**
+ * Generated binding.
+ * The cosine of a number in radians.
+ */
+export type CosExpression = { number: AnyExpression };
+
+/**
+ * Generated binding.
+ * The absolute value of a number.
+ */
+export type AbsExpression = { number: AnyExpression };
+
+/**
+ * Generated binding.
+ * The smaller of two numbers.
+ */
+export type MinExpression = { left: AnyExpression, right: AnyExpression };
+
+/**
+ * Generated binding.
+ * The larger of two numbers.
+ */
+export type MaxExpression = { left: AnyExpression, right: AnyExpression };
+
+/**
+ * Generated binding.
+ * Limits a number to a range.
+ */
+export type ClampExpression = { number: AnyExpression, min: AnyExpression, max: AnyExpression };
+
+/**
+ * Generated binding.
+ * The remainder of dividing two numbers. The result is never negative, so it's useful for looping over time.
+ */
+export type ModuloExpression = { left: AnyExpression, right: AnyExpression };
+
+/**
+ * Generated binding.
+ * A number raised to a power.
+ */
+export type PowerExpression = { base: AnyExpression, exponent: AnyExpression };
+
+/**
+ * Generated binding.
+ * Linearly interpolates between two numbers. `t` isn't clamped, so values outside 0-1 extrapolate.
+ */
+export type LerpExpression = { start: AnyExpression, end: AnyExpression, t: AnyExpression };
+
+/**
+ * Generated binding.
+ * Smoothly goes from 0 to 1 as the number goes from `start` to `end`.
+ */
+export type SmoothstepExpression = { start: AnyExpression, end: AnyExpression, number: AnyExpression };
+
+/**
+ * Generated binding.
+ * Applies an easing curve to a number from 0 to 1.
+ */
+export type EaseExpression = { curve: EasingCurve, number: AnyExpression };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * A standard easing curve, mapping 0-1 to 0-1.
+ */
+export type EasingCurve = { "type": "Linear" } | { "type": "InQuad" } | { "type": "OutQuad" } | { "type": "InOutQuad" } | { "type": "InCubic" } | { "type": "OutCubic" } | { "type": "InOutCubic" } | { "type": "InSine" } | { "type": "OutSine" } | { "type": "InOutSine" } | { "type": "InExpo" } | { "type": "OutExpo" } | { "type": "InOutExpo" } | { "type": "InBack" } | { "type": "OutBack" } | { "type": "InOutBack" } | { "type": "InBounce" } | { "type": "OutBounce" } | { "type": "InOutBounce" };
+
+/**
+ * Generated binding.
+ * Interpolates between keyframes, using the input as the time; usually, the input is `time`.
+ * Keyframes are sorted by time when they're loaded.
+ * Written as `keyframes(input, time, value, time, value, ...)`, or `keyframes_loop` and `keyframes_ping_pong`
+ * for the other modes, in the text form of expressions. A keyframe's easing is written by wrapping its value,
+ * like `keyframes(time, 0, 0, 5, ease_in_quad(255))`.
+ */
+export type KeyframesExpression = { input: AnyExpression, keyframes: Array<Keyframe>, mode: KeyframeMode };
+
+/**
+ * Generated binding.
+ * A value at a point in time.
+ */
+export type Keyframe = { time: number, value: number, 
+/**
+ * The easing curve used to reach this keyframe's value from the previous one. Defaults to linear.
+ */
+easing: EasingCurve | null };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * What a keyframes expression does outside the time range of its keyframes.
+ */
+export type KeyframeMode = { "type": "Hold" } | { "type": "Loop" } | { "type": "PingPong" };
+
+/**
+ * Generated binding.
+ * Picks between two expressions: `if_true` when the condition is greater than 0, and `if_false` otherwise.
+ * Only the chosen expression is computed.
+ */
+export type SelectExpression = { condition: AnyExpression, if_true: AnyExpression, if_false: AnyExpression };
+
+/**
+ * Generated binding.
+ * A random number from 0 to 1. Without an input, a new number is generated every time it's computed.
+ * With an input, the number only changes when the input crosses a whole number, so `random(1, pixel)`
+ * gives every pixel its own random value that stays the same across frames.
+ */
+export type RandomExpression = { seed: number, input: AnyExpression | null };
+
+/**
+ * Generated binding.
+ * Smooth noise from 0 to 1 with a feature size of 1 unit. With only `x`, the noise is 1D (for example,
+ * over time); with `y` as well, it's 2D (for example, over time and the pixel's position).
+ */
+export type NoiseExpression = { kind: NoiseKind, seed: number, x: AnyExpression, y: AnyExpression | null };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * The kind of smooth noise a noise expression generates.
+ */
+export type NoiseKind = { "type": "Value" } | { "type": "Perlin" };
+
+/**
+ * Generated binding.
+ * A gradient of color stops that effects and color expressions can sample by position.
+ * A plain list of colors, like `[{ "r": 255, "g": 0, "b": 0, "alpha": 1 }, ...]`, is deserialized as evenly
+ * spaced stops that are blended in RGB without wrapping.
+ */
+export type Gradient = { stops: Array<ColorStop>, interpolation: GradientInterpolation, 
+/**
+ * Whether positions outside 0 to 1 repeat the gradient instead of being clamped, with the last stop blending back into the first.
+ */
+wrap: boolean };
+
+/**
+ * Generated binding.
+ * A color at a position in a gradient.
+ */
+export type ColorStop = { 
+/**
+ * The position of the stop, from 0 to 1.
+ */
+position: number, color: AnyColorExpression };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * A wrapper for any color expression.
+ * Used for serialization and deserialization.
+ * A plain color, like `{ "r": 255, "g": 0, "b": 0, "alpha": 1 }`, is deserialized as a constant.
+ */
+export type AnyColorExpression = { "type": "Constant" } & ConstantColorExpression | { "type": "RGB" } & RGBColorExpression | { "type": "HSL" } & HSLColorExpression | { "type": "Gradient" } & GradientColorExpression | { "type": "Lerp" } & LerpColorExpression;
+
+/**
+ * Generated binding.
+ * A constant color.
+ */
+export type ConstantColorExpression = { color: PixelColor };
+
+/**
+ * Generated binding.
+ * A pixel is a single unit of color data with an alpha value.
+ */
+export type PixelColor = { r: number, g: number, b: number, alpha: number };
+
+/**
+ * Generated binding.
+ * A color from expressions for its red, green, and blue channels, from 0 to 1.
+ */
+export type RGBColorExpression = { red: AnyExpression, green: AnyExpression, blue: AnyExpression, 
+/**
+ * The alpha of the color, from 0 to 1. Defaults to fully opaque.
+ */
+alpha: AnyExpression | null };
+
+/**
+ * Generated binding.
+ * A color from expressions for its hue, saturation, and lightness.
+ * Hue is in degrees and wraps around; saturation and lightness are from 0 to 1.
+ */
+export type HSLColorExpression = { hue: AnyExpression, saturation: AnyExpression, lightness: AnyExpression, 
+/**
+ * The alpha of the color, from 0 to 1. Defaults to fully opaque.
+ */
+alpha: AnyExpression | null };
+
+/**
+ * Generated binding.
+ * Looks up a color in a gradient. With a wrapping gradient, a position that increases over time cycles through the colors forever.
+ */
+export type GradientColorExpression = { gradient: Gradient, position: AnyExpression };
+
+/**
+ * Generated binding.
+ * Blends between two colors. `t` is clamped from 0 to 1.
+ */
+export type LerpColorExpression = { start: AnyColorExpression, end: AnyColorExpression, t: AnyExpression };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * How colors are blended between the stops of a gradient.
+ */
+export type GradientInterpolation = { "type": "RGB" } | { "type": "HSL" } | { "type": "OKLab" };
+
+/**
+ * Generated binding.
+ * The music visualizer effect renders the audio data received from the
+ * music visualizer client as a visualizer.
+ */
+export type MusicVisualizerEffect = { 
+/**
+ * The colors along the strip, with the lightness set by the audio level. If not set, a rainbow is used.
+ */
+gradient: Gradient | null };
+
+/**
+ * Generated binding.
+ */
+export type RotateEffect = { 
+/**
+ * The effect to rotate
+ */
+effect: AnyEffect, 
+/**
+ * The number of pixels to rotate the frame by. If negative, it rotates to the left.
+ */
+rotation: AnyExpression };
+
 /**
  * Generated binding.
  */
@@ -138,19 +395,19 @@ export type FlashingColorEffect = {
 /**
  * The offset of the flashing effect, in seconds.
  */
-offset: number, 
+offset: AnyExpression, 
 /**
  * The speed of the flashing effect, in Hz.
  */
-speed: number, 
+speed: AnyExpression, 
 /**
  * The color of the first flashing color.
  */
-color_a: PixelColor, 
+color_a: AnyColorExpression, 
 /**
  * The color of the second flashing color.
  */
-color_b: PixelColor };
+color_b: AnyColorExpression };
 
 /**
  * Generated binding.
@@ -159,15 +416,269 @@ export type SolidColorEffect = {
 /**
  * The color to make every pixel.
  */
-color: PixelColor, 
+color: AnyColorExpression, 
 /**
  * The start pixel index.
  */
-start: number, 
+start: AnyExpression, 
 /**
  * The stop pixel index.
  */
-stop: number };
+stop: AnyExpression };
+
+/**
+ * Generated binding.
+ * Fills the strip with white light at a color temperature, corrected with the installation's white calibration
+ * so it matches light bulbs of the same temperature.
+ */
+export type WhiteEffect = { 
+/**
+ * The color temperature, in Kelvin. Warm white bulbs are around 2700K and daylight is around 6500K.
+ */
+temperature: AnyExpression, 
+/**
+ * The brightness, from 0 to 1.
+ */
+brightness: AnyExpression };
+
+/**
+ * Generated binding.
+ */
+export type ExpressionColorEffect = { 
+/**
+ * The color expression computed for every pixel to get its color.
+ */
+color: AnyColorExpression, 
+/**
+ * The alpha of every pixel, from 0 to 1. Overrides the alpha of the color if set.
+ */
+alpha: AnyExpression | null };
+
+/**
+ * Generated binding.
+ * A gradient along a direction in the room, following the room's geometry instead of the strip's wiring.
+ */
+export type LinearGradientEffect = { 
+/**
+ * The colors of the gradient. Wrapping gradients repeat along the direction.
+ */
+gradient: Gradient, 
+/**
+ * The direction of the gradient, in degrees. 0 points along the x axis and 90 along the y axis.
+ */
+angle: AnyExpression, 
+/**
+ * The distance the gradient spans from position 0 to 1, in meters.
+ */
+length: AnyExpression, 
+/**
+ * How far the gradient is moved along its direction from the room's origin, in meters.Increasing it over time makes the gradient travel.
+ */
+offset: AnyExpression };
+
+/**
+ * Generated binding.
+ * A gradient outward from a point in the room.
+ */
+export type RadialGradientEffect = { 
+/**
+ * The colors of the gradient from the center outward. Wrapping gradients repeat as rings.
+ */
+gradient: Gradient, 
+/**
+ * The center of the gradient in the room, in meters.
+ */
+center_x: AnyExpression, center_y: AnyExpression, 
+/**
+ * The distance from the center to position 1 of the gradient, in meters.
+ */
+radius: AnyExpression, 
+/**
+ * How far the gradient is moved outward, in meters. Increasing it over time makes rings expand from the center.
+ */
+offset: AnyExpression };
+
+/**
+ * Generated binding.
+ * A sine wave that travels across the room in a straight line, blending between two colors.
+ */
+export type PlaneWaveEffect = { 
+/**
+ * The color at the troughs of the wave.
+ */
+color_a: AnyColorExpression, 
+/**
+ * The color at the crests of the wave.
+ */
+color_b: AnyColorExpression, 
+/**
+ * The direction the wave travels in, in degrees. 0 points along the x axis and 90 along the y axis.
+ */
+angle: AnyExpression, 
+/**
+ * The distance between crests, in meters.
+ */
+wavelength: AnyExpression, 
+/**
+ * How fast the wave travels, in meters per second.
+ */
+speed: AnyExpression };
+
+/**
+ * Generated binding.
+ * A procedural fire simulation. Heat is simulated separately along every span in the spatial map:
+ * every frame, each pixel cools down, heat drifts up away from the base, and new sparks randomly ignite near the base.
+ */
+export type FireEffect = { 
+/**
+ * How quickly the flames cool, from 0 to 1. Higher values make shorter flames.
+ */
+cooling: AnyExpression, 
+/**
+ * The chance of a new spark near the base of each flame every frame, from 0 to 1.
+ */
+sparking: AnyExpression, 
+/**
+ * The colors heat is mapped to, from coldest at 0 to hottest at 1.
+ */
+palette: Gradient, 
+/**
+ * Which end of each span the flames rise from.
+ */
+base: FireBase };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * Which end of each span in the spatial map the flames rise from.
+ */
+export type FireBase = { "type": "Start" } | { "type": "End" } | { "type": "Both" };
+
+/**
+ * Generated binding.
+ * Random pixels fade in and out with colors from a palette. Pixels that aren't twinkling are transparent.
+ */
+export type TwinkleEffect = { 
+/**
+ * The average fraction of pixels twinkling at once, from 0 to 1.
+ */
+density: AnyExpression, 
+/**
+ * How long a twinkle takes to fade in, in seconds.
+ */
+fade_in: AnyExpression, 
+/**
+ * How long a twinkle takes to fade out, in seconds.
+ */
+fade_out: AnyExpression, 
+/**
+ * The colors of the twinkles. Every twinkle picks a random position in the palette.
+ */
+palette: Gradient, 
+/**
+ * The seed for which pixels twinkle, so the effect looks the same every time it runs.
+ */
+seed: number };
+
+/**
+ * Generated binding.
+ * One or more comets with exponentially fading tails that chase each other along the strip.
+ */
+export type CometEffect = { 
+/**
+ * The number of comets, evenly spaced along the path, up to 32.
+ */
+heads: number, 
+/**
+ * How fast the comets move, in pixels or meters per second depending on the path. Negative speeds move backward.
+ */
+speed: AnyExpression, 
+/**
+ * The length of each tail, in pixels or meters depending on the path.
+ */
+tail_length: AnyExpression, 
+/**
+ * The colors of each comet, from 0 at the head to 1 at the end of the tail.
+ */
+colors: Gradient, mode: CometMode, path: CometPath };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * What the comets do when they reach the end of their path.
+ */
+export type CometMode = { "type": "Wrap" } | { "type": "Bounce" };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * The path the comets travel along.
+ */
+export type CometPath = { "type": "Strip" } | { "type": "Perimeter" };
+
+/**
+ * Generated binding.
+ * Plays an image or animated GIF on the strip.
+ */
+export type ImageEffect = { 
+/**
+ * The name of a GIF uploaded to the controller with `PUT /api/image/:name`.
+ */
+image: string, mapping: ImageMapping, 
+/**
+ * How fast the image plays. 1 is the speed the GIF was made for.
+ */
+speed: AnyExpression, 
+/**
+ * Whether the image starts over once it ends. Otherwise, it stays on the last frame.
+ */
+looping: boolean };
+
+/**
+ * Generated binding.
+ * Tagged with "type".
+ * How an image is mapped onto the pixels.
+ */
+export type ImageMapping = { "type": "Rows" } & RowsImageMapping | { "type": "Project" };
+
+/**
+ * Generated binding.
+ * Shows one row of the image at a time, stretched across the strip, moving down the image over time.
+ */
+export type RowsImageMapping = { 
+/**
+ * How many rows are shown every second.
+ */
+rows_per_second: number };
+
+/**
+ * Generated binding.
+ * Shows the time of day around the room like the face of a clock, with the hands placed by their angle from the center of the room.
+ * Uses the controller's local time zone.
+ */
+export type ClockEffect = { hour: ClockMarker | null, minute: ClockMarker | null, second: ClockMarker | null, 
+/**
+ * Markers at every hour, drawn under the hands.
+ */
+ticks: ClockMarker | null, 
+/**
+ * The direction of 12 o'clock from the center of the room, in degrees. 0 points along the x axis and 90 along the y axis.
+ */
+rotation: number, 
+/**
+ * Whether the hands move clockwise when looking down at the room with the y axis pointing up.
+ */
+clockwise: boolean };
+
+/**
+ * Generated binding.
+ * A marker on the clock, like a hand or the hour ticks.
+ */
+export type ClockMarker = { color: AnyColorExpression, 
+/**
+ * The width of the marker around the room, in degrees. Markers fade out toward their edges.
+ */
+width: number };
 
 /**
  * Generated binding.
@@ -180,35 +691,73 @@ export type WebsocketInputEffect = {  };
  * This is by far the most complex effect type, as it allows for arbitrary
  * calculations for every pixel in the frame.
  */
-export type NodeEditorEffect = { nodes: { [key in string]?: Node } };
+export type NodeEditorEffect = { nodes: { [key in string]?: NodeData }, 
+/**
+ * The node output that is displayed. A color output is evaluated for every pixel, while a frame output is used directly.
+ */
+output: NodeConnection | null };
 
 /**
  * Generated binding.
  */
-export type Node = { id: string, implementation: AnyNodeImplementation, inputs: Array<[string, number]>, last_frame_rendered: number };
+export type NodeData = { type: string, inputs: Array<NodeConnection | null>, parameters: { [key in string]?: NodeParameter } };
 
 /**
  * Generated binding.
- * Tagged with "type".
+ * A connection from an output port of another node.
+ */
+export type NodeConnection = { 
+/**
+ * The node the value comes from.
+ */
+node: string, 
+/**
+ * The index of the output port on that node.
  */
-export type AnyNodeImplementation = { "type": "LiteralNode" } & LiteralNode;
+output: number };
 
 /**
  * Generated binding.
+ * Tagged with "type".
+ * A value configured in the editor rather than connected from another node.
  */
-export type LiteralNode = { value: Value };
+export type NodeParameter = { "type": "Float", "value": number } | { "type": "Int", "value": number } | { "type": "Bool", "value": boolean } | { "type": "String", "value": string } | { "type": "Color", "value": PixelColor } | { "type": "Effect", "value": AnyEffect } | { "type": "Type", "value": TypeInfo } | { "type": "Group", "value": NodeGroup };
 
 /**
  * Generated binding.
  * Tagged with "type".
  */
-export type Value = { "type": "Float" } & number | { "type": "Integer" } & number | { "type": "Color" } & PixelColor | { "type": "Boolean" } & boolean | { "type": "Frame" } & Frame;
+export type TypeInfo = { "type": "Int" } | { "type": "Float" } | { "type": "Bool" } | { "type": "String" } | { "type": "Color" } | { "type": "Frame" };
 
 /**
  * Generated binding.
- * A frame is a single set of pixel data.
+ * A reusable subgraph that is used like a single node.
+ * Groups are saved in a library alongside the effect presets, and every group node holds its own copy of the group.
  */
-export type Frame = { pixel_data: Array<PixelColor> };
+export type NodeGroup = { 
+/**
+ * The inputs exposed by the group. Group input nodes inside the group read these by index.
+ */
+inputs: Array<PortInfo>, 
+/**
+ * The outputs exposed by the group.
+ */
+outputs: Array<GroupOutput>, nodes: { [key in string]?: NodeData } };
+
+/**
+ * Generated binding.
+ */
+export type PortInfo = { name: string, type_info: TypeInfo };
+
+/**
+ * Generated binding.
+ * An output exposed by a node group.
+ */
+export type GroupOutput = { name: string, type_info: TypeInfo, 
+/**
+ * The node output inside the group that provides the value. Unconnected outputs use a default value.
+ */
+source: NodeConnection | null };
 
 /**
  * Generated binding.
@@ -216,10 +765,86 @@ export type Frame = { pixel_data: Array<PixelColor> };
  * A wrapper for any temporary effect that can be rendered.
  * Used for serialization and deserialization.
  */
-export type AnyTemporaryEffect = { "type": "TemporaryEffectWrapper" } & DurationTemporaryEffect;
+export type AnyTemporaryEffect = { "type": "TemporaryEffectWrapper" } & DurationTemporaryEffect | { "type": "Ripple" } & RippleTemporaryEffect | { "type": "Sunrise" } & SunriseTemporaryEffect;
 
 /**
  * Generated binding.
  */
 export type DurationTemporaryEffect = { duration: number, effect: AnyEffect };
 
+/**
+ * Generated binding.
+ * Rings of color that expand from a point in the room and fade out as they reach the farthest pixel.
+ * Finishes once the last ring has faded out.
+ */
+export type RippleTemporaryEffect = { 
+/**
+ * The point the rings expand from.
+ */
+origin: Location, 
+/**
+ * The color of the rings.
+ */
+color: AnyColorExpression, 
+/**
+ * How fast the rings expand, in meters per second.
+ */
+speed: number, 
+/**
+ * The width of each ring, in meters.
+ */
+width: number, 
+/**
+ * The number of rings, up to 16.
+ */
+rings: number, 
+/**
+ * The time between rings, in seconds.
+ */
+interval: number };
+
+/**
+ * Generated binding.
+ * A 2D location, with x and y coordinates in meters.
+ */
+export type Location = { x: number, y: number };
+
+/**
+ * Generated binding.
+ * Fades the whole strip in from dark, warm light to bright, cool light, like a sunrise. Overrides everything under it.
+ * Once the sunrise is over, it stays at full brightness for a while before finishing, unless it's dismissed first.
+ */
+export type SunriseTemporaryEffect = { 
+/**
+ * How long the sunrise takes, in seconds.
+ */
+duration: number, 
+/**
+ * How long the light stays on after the sunrise, in seconds.
+ */
+hold: number, 
+/**
+ * The color temperature at the start of the sunrise, in Kelvin.
+ */
+start_temperature: number, 
+/**
+ * The color temperature at the end of the sunrise, in Kelvin.
+ */
+end_temperature: number, 
+/**
+ * The brightness at the end of the sunrise, from 0 to 1.
+ */
+brightness: number };
+
+/**
+ * Generated binding.
+ * The serialized form of a node. Nodes are recreated from the registry by their type name.
+ */
+export type SerializedNodeData = { type: string, inputs: Array<NodeConnection | null>, parameters: { [key in string]?: NodeParameter } };
+
+/**
+ * Generated binding.
+ * A frame is a single set of pixel data.
+ */
+export type Frame = { pixel_data: Array<PixelColor> };
+
@@ -63,6 +63,76 @@ export const AnyEffectSchema: Schema = {
           "content": "SolidColorEffect"
         }
       },
+      {
+        "name": "White",
+        "value": {
+          "type": "Reference",
+          "content": "WhiteEffect"
+        }
+      },
+      {
+        "name": "ExpressionColor",
+        "value": {
+          "type": "Reference",
+          "content": "ExpressionColorEffect"
+        }
+      },
+      {
+        "name": "LinearGradient",
+        "value": {
+          "type": "Reference",
+          "content": "LinearGradientEffect"
+        }
+      },
+      {
+        "name": "RadialGradient",
+        "value": {
+          "type": "Reference",
+          "content": "RadialGradientEffect"
+        }
+      },
+      {
+        "name": "PlaneWave",
+        "value": {
+          "type": "Reference",
+          "content": "PlaneWaveEffect"
+        }
+      },
+      {
+        "name": "Fire",
+        "value": {
+          "type": "Reference",
+          "content": "FireEffect"
+        }
+      },
+      {
+        "name": "Twinkle",
+        "value": {
+          "type": "Reference",
+          "content": "TwinkleEffect"
+        }
+      },
+      {
+        "name": "Comet",
+        "value": {
+          "type": "Reference",
+          "content": "CometEffect"
+        }
+      },
+      {
+        "name": "Image",
+        "value": {
+          "type": "Reference",
+          "content": "ImageEffect"
+        }
+      },
+      {
+        "name": "Clock",
+        "value": {
+          "type": "Reference",
+          "content": "ClockEffect"
+        }
+      },
       {
         "name": "WebsocketInput",
         "value": {
@@ -138,115 +208,29 @@ export const StripeEffectSchema: Schema = {
     {
       "name": "stripe_width",
       "ty": {
-        "type": "Number"
+        "type": "Reference",
+        "content": "AnyExpression"
       },
       "docs": "The width of each stripe in pixels."
     },
     {
       "name": "stripe_colors",
-      "ty": {
-        "type": "ArrayOf",
-        "content": {
-          "type": "Reference",
-          "content": "PixelColor"
-        }
-      },
-      "docs": "The colors of the stripes."
-    }
-  ]
-};
-schemas["StripeEffect"] = StripeEffectSchema;
-
-/**
- * Generated schema.
- * A pixel is a single unit of color data with an alpha value.
- */
-export const PixelColorSchema: Schema = {
-  "type": "Struct",
-  "content": [
-    {
-      "name": "r",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": null
-    },
-    {
-      "name": "g",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": null
-    },
-    {
-      "name": "b",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": null
-    },
-    {
-      "name": "alpha",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": null
-    }
-  ]
-};
-schemas["PixelColor"] = PixelColorSchema;
-
-/**
- * Generated schema.
- * The music visualizer effect runs a TCP socket server that listens for
- * audio data from the music visualizer client. Then, it renders the audio
- * data as a visualizer.
- */
-export const MusicVisualizerEffectSchema: Schema = {
-  "type": "Struct",
-  "content": [
-    {
-      "name": "port",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": "The UDP listener that listens for audio data from the music visualizer client."
-    }
-  ]
-};
-schemas["MusicVisualizerEffect"] = MusicVisualizerEffectSchema;
-
-/**
- * Generated schema.
- */
-export const RotateEffectSchema: Schema = {
-  "type": "Struct",
-  "content": [
-    {
-      "name": "effect",
-      "ty": {
-        "type": "Reference",
-        "content": "AnyEffect"
-      },
-      "docs": "The effect to rotate"
-    },
-    {
-      "name": "rotation",
       "ty": {
         "type": "Reference",
-        "content": "AnyExpression"
+        "content": "Gradient"
       },
-      "docs": "The number of pixels to rotate the frame by. If negative, it rotates to the left."
+      "docs": "The colors of the stripes: one stripe for every stop, sampled evenly along the gradient."
     }
   ]
 };
-schemas["RotateEffect"] = RotateEffectSchema;
+schemas["StripeEffect"] = StripeEffectSchema;
 
 /**
  * Generated schema.
  * Tagged with "type".
  * A wrapper for any expression.
  * Used for serialization and deserialization.
+ * Expressions can also be deserialized from their text form, like `"time * 84"`, or from a plain number.
  */
 export const AnyExpressionSchema: Schema = {
   "type": "Enum",
@@ -266,6 +250,55 @@ export const AnyExpressionSchema: Schema = {
           "content": "CurrentTimeExpression"
         }
       },
+      {
+        "name": "FrameCount",
+        "value": {
+          "type": "Reference",
+          "content": "FrameCountExpression"
+        }
+      },
+      {
+        "name": "PixelIndex",
+        "value": {
+          "type": "Reference",
+          "content": "PixelIndexExpression"
+        }
+      },
+      {
+        "name": "PixelPosition",
+        "value": {
+          "type": "Reference",
+          "content": "PixelPositionExpression"
+        }
+      },
+      {
+        "name": "PixelX",
+        "value": {
+          "type": "Reference",
+          "content": "PixelXExpression"
+        }
+      },
+      {
+        "name": "PixelY",
+        "value": {
+          "type": "Reference",
+          "content": "PixelYExpression"
+        }
+      },
+      {
+        "name": "Variable",
+        "value": {
+          "type": "Reference",
+          "content": "VariableExpression"
+        }
+      },
+      {
+        "name": "Audio",
+        "value": {
+          "type": "Reference",
+          "content": "AudioExpression"
+        }
+      },
       {
         "name": "Add",
         "value": {
@@ -314,6 +347,111 @@ export const AnyExpressionSchema: Schema = {
           "type": "Reference",
           "content": "CeilExpression"
         }
+      },
+      {
+        "name": "Sin",
+        "value": {
+          "type": "Reference",
+          "content": "SinExpression"
+        }
+      },
+      {
+        "name": "Cos",
+        "value": {
+          "type": "Reference",
+          "content": "CosExpression"
+        }
+      },
+      {
+        "name": "Abs",
+        "value": {
+          "type": "Reference",
+          "content": "AbsExpression"
+        }
+      },
+      {
+        "name": "Min",
+        "value": {
+          "type": "Reference",
+          "content": "MinExpression"
+        }
+      },
+      {
+        "name": "Max",
+        "value": {
+          "type": "Reference",
+          "content": "MaxExpression"
+        }
+      },
+      {
+        "name": "Clamp",
+        "value": {
+          "type": "Reference",
+          "content": "ClampExpression"
+        }
+      },
+      {
+        "name": "Modulo",
+        "value": {
+          "type": "Reference",
+          "content": "ModuloExpression"
+        }
+      },
+      {
+        "name": "Power",
+        "value": {
+          "type": "Reference",
+          "content": "PowerExpression"
+        }
+      },
+      {
+        "name": "Lerp",
+        "value": {
+          "type": "Reference",
+          "content": "LerpExpression"
+        }
+      },
+      {
+        "name": "Smoothstep",
+        "value": {
+          "type": "Reference",
+          "content": "SmoothstepExpression"
+        }
+      },
+      {
+        "name": "Ease",
+        "value": {
+          "type": "Reference",
+          "content": "EaseExpression"
+        }
+      },
+      {
+        "name": "Keyframes",
+        "value": {
+          "type": "Reference",
+          "content": "KeyframesExpression"
+        }
+      },
+      {
+        "name": "Select",
+        "value": {
+          "type": "Reference",
+          "content": "SelectExpression"
+        }
+      },
+      {
+        "name": "Random",
+        "value": {
+          "type": "Reference",
+          "content": "RandomExpression"
+        }
+      },
+      {
+        "name": "Noise",
+        "value": {
+          "type": "Reference",
+          "content": "NoiseExpression"
+        }
       }
     ],
     "tag_name": "type",
@@ -352,15 +490,140 @@ schemas["CurrentTimeExpression"] = CurrentTimeExpressionSchema;
 
 /**
  * Generated schema.
+ * Gets the number of frames rendered so far.
  */
-export const AddExpressionSchema: Schema = {
+export const FrameCountExpressionSchema: Schema = {
   "type": "Struct",
-  "content": [
-    {
-      "name": "left",
-      "ty": {
-        "type": "Reference",
-        "content": "AnyExpression"
+  "content": []
+};
+schemas["FrameCountExpression"] = FrameCountExpressionSchema;
+
+/**
+ * Generated schema.
+ * Gets the index of the pixel being computed.
+ */
+export const PixelIndexExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": []
+};
+schemas["PixelIndexExpression"] = PixelIndexExpressionSchema;
+
+/**
+ * Generated schema.
+ * Gets the position of the pixel being computed along the strip, from 0 to 1.
+ */
+export const PixelPositionExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": []
+};
+schemas["PixelPositionExpression"] = PixelPositionExpressionSchema;
+
+/**
+ * Generated schema.
+ * Gets the x coordinate of the pixel being computed in the room, in meters.
+ */
+export const PixelXExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": []
+};
+schemas["PixelXExpression"] = PixelXExpressionSchema;
+
+/**
+ * Generated schema.
+ * Gets the y coordinate of the pixel being computed in the room, in meters.
+ */
+export const PixelYExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": []
+};
+schemas["PixelYExpression"] = PixelYExpressionSchema;
+
+/**
+ * Generated schema.
+ * Gets the value of a variable set at runtime, or 0 if it isn't set.
+ * Written as `$name` in the text form of expressions.
+ */
+export const VariableExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "name",
+      "ty": {
+        "type": "String"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["VariableExpression"] = VariableExpressionSchema;
+
+/**
+ * Generated schema.
+ * Gets a value from the latest audio data published by the music visualizer.
+ * Values fall to 0 when no music visualizer is running.
+ */
+export const AudioExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "value",
+      "ty": {
+        "type": "Reference",
+        "content": "AudioValue"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["AudioExpression"] = AudioExpressionSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * A value computed from the latest audio data, from 0 to 1.
+ */
+export const AudioValueSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Level",
+        "value": null
+      },
+      {
+        "name": "Bass",
+        "value": null
+      },
+      {
+        "name": "Mid",
+        "value": null
+      },
+      {
+        "name": "Treble",
+        "value": null
+      },
+      {
+        "name": "Beat",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["AudioValue"] = AudioValueSchema;
+
+/**
+ * Generated schema.
+ */
+export const AddExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "left",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
       },
       "docs": null
     },
@@ -510,252 +773,1844 @@ schemas["CeilExpression"] = CeilExpressionSchema;
 
 /**
  * Generated schema.
+ * The sine of a number in radians.
  */
-export const FlashingColorEffectSchema: Schema = {
+export const SinExpressionSchema: Schema = {
   "type": "Struct",
   "content": [
     {
-      "name": "offset",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": "The offset of the flashing effect, in seconds."
-    },
-    {
-      "name": "speed",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": "The speed of the flashing effect, in Hz."
-    },
-    {
-      "name": "color_a",
-      "ty": {
-        "type": "Reference",
-        "content": "PixelColor"
-      },
-      "docs": "The color of the first flashing color."
-    },
-    {
-      "name": "color_b",
+      "name": "number",
       "ty": {
         "type": "Reference",
-        "content": "PixelColor"
+        "content": "AnyExpression"
       },
-      "docs": "The color of the second flashing color."
+      "docs": null
     }
   ]
 };
-schemas["FlashingColorEffect"] = FlashingColorEffectSchema;
+schemas["SinExpression"] = SinExpressionSchema;
 
 /**
  * Generated schema.
+ * The cosine of a number in radians.
  */
-export const SolidColorEffectSchema: Schema = {
+export const CosExpressionSchema: Schema = {
   "type": "Struct",
   "content": [
     {
-      "name": "color",
+      "name": "number",
       "ty": {
         "type": "Reference",
-        "content": "PixelColor"
-      },
-      "docs": "The color to make every pixel."
-    },
-    {
-      "name": "start",
-      "ty": {
-        "type": "Number"
-      },
-      "docs": "The start pixel index."
-    },
-    {
-      "name": "stop",
-      "ty": {
-        "type": "Number"
+        "content": "AnyExpression"
       },
-      "docs": "The stop pixel index."
+      "docs": null
     }
   ]
 };
-schemas["SolidColorEffect"] = SolidColorEffectSchema;
-
-/**
- * Generated schema.
- */
-export const WebsocketInputEffectSchema: Schema = {
-  "type": "Struct",
-  "content": []
-};
-schemas["WebsocketInputEffect"] = WebsocketInputEffectSchema;
+schemas["CosExpression"] = CosExpressionSchema;
 
 /**
  * Generated schema.
- * An effect that renders a frame based on a node-based graphical editor.
- * This is by far the most complex effect type, as it allows for arbitrary
- * calculations for every pixel in the frame.
+ * The absolute value of a number.
  */
-export const NodeEditorEffectSchema: Schema = {
+export const AbsExpressionSchema: Schema = {
   "type": "Struct",
   "content": [
     {
-      "name": "nodes",
+      "name": "number",
       "ty": {
         "type": "Reference",
-        "content": "Node"
+        "content": "AnyExpression"
       },
       "docs": null
     }
   ]
 };
-schemas["NodeEditorEffect"] = NodeEditorEffectSchema;
+schemas["AbsExpression"] = AbsExpressionSchema;
 
 /**
  * Generated schema.
+ * The smaller of two numbers.
  */
-export const NodeSchema: Schema = {
+export const MinExpressionSchema: Schema = {
   "type": "Struct",
   "content": [
     {
-      "name": "id",
+      "name": "left",
       "ty": {
         "type": "Reference",
-        "content": "NodeID"
+        "content": "AnyExpression"
       },
       "docs": null
     },
     {
-      "name": "implementation",
+      "name": "right",
       "ty": {
         "type": "Reference",
-        "content": "AnyNodeImplementation"
+        "content": "AnyExpression"
       },
       "docs": null
-    },
+    }
+  ]
+};
+schemas["MinExpression"] = MinExpressionSchema;
+
+/**
+ * Generated schema.
+ * The larger of two numbers.
+ */
+export const MaxExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
     {
-      "name": "inputs",
+      "name": "left",
       "ty": {
-        "type": "ArrayOf",
-        "content": {
-          "type": "TupleOf",
-          "content": [
-            {
-              "type": "Reference",
-              "content": "NodeID"
-            },
-            {
-              "type": "Number"
-            }
-          ]
-        }
+        "type": "Reference",
+        "content": "AnyExpression"
       },
       "docs": null
     },
     {
-      "name": "last_frame_rendered",
+      "name": "right",
       "ty": {
-        "type": "Number"
+        "type": "Reference",
+        "content": "AnyExpression"
       },
       "docs": null
     }
   ]
 };
-schemas["Node"] = NodeSchema;
-
-/**
- * Generated schema.
- * Tagged with "type".
- */
-export const AnyNodeImplementationSchema: Schema = {
-  "type": "Enum",
-  "content": {
-    "variants": [
-      {
-        "name": "LiteralNode",
-        "value": {
-          "type": "Reference",
-          "content": "LiteralNode"
-        }
-      }
-    ],
-    "tag_name": "type",
-    "content_subfield": null
-  }
-};
-schemas["AnyNodeImplementation"] = AnyNodeImplementationSchema;
-
-/**
- * Generated schema.
- * Tagged with "type".
- * A wrapper for any temporary effect that can be rendered.
- * Used for serialization and deserialization.
- */
-export const AnyTemporaryEffectSchema: Schema = {
-  "type": "Enum",
-  "content": {
-    "variants": [
-      {
-        "name": "TemporaryEffectWrapper",
-        "value": {
-          "type": "Reference",
-          "content": "DurationTemporaryEffect"
-        }
-      }
-    ],
-    "tag_name": "type",
-    "content_subfield": null
-  }
-};
-schemas["AnyTemporaryEffect"] = AnyTemporaryEffectSchema;
+schemas["MaxExpression"] = MaxExpressionSchema;
 
 /**
  * Generated schema.
+ * Limits a number to a range.
  */
-export const DurationTemporaryEffectSchema: Schema = {
+export const ClampExpressionSchema: Schema = {
   "type": "Struct",
   "content": [
     {
-      "name": "duration",
+      "name": "number",
       "ty": {
-        "type": "Number"
+        "type": "Reference",
+        "content": "AnyExpression"
       },
       "docs": null
     },
     {
-      "name": "effect",
+      "name": "min",
       "ty": {
         "type": "Reference",
-        "content": "AnyEffect"
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "max",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
       },
       "docs": null
     }
   ]
 };
-schemas["DurationTemporaryEffect"] = DurationTemporaryEffectSchema;
+schemas["ClampExpression"] = ClampExpressionSchema;
 
 /**
  * Generated schema.
+ * The remainder of dividing two numbers. The result is never negative, so it's useful for looping over time.
  */
-export const LiteralNodeSchema: Schema = {
+export const ModuloExpressionSchema: Schema = {
   "type": "Struct",
   "content": [
     {
-      "name": "value",
+      "name": "left",
       "ty": {
         "type": "Reference",
-        "content": "Value"
+        "content": "AnyExpression"
       },
       "docs": null
-    }
-  ]
-};
-schemas["LiteralNode"] = LiteralNodeSchema;
-
-/**
- * Generated schema.
+    },
+    {
+      "name": "right",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["ModuloExpression"] = ModuloExpressionSchema;
+
+/**
+ * Generated schema.
+ * A number raised to a power.
+ */
+export const PowerExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "base",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "exponent",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["PowerExpression"] = PowerExpressionSchema;
+
+/**
+ * Generated schema.
+ * Linearly interpolates between two numbers. `t` isn't clamped, so values outside 0-1 extrapolate.
+ */
+export const LerpExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "start",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "end",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "t",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["LerpExpression"] = LerpExpressionSchema;
+
+/**
+ * Generated schema.
+ * Smoothly goes from 0 to 1 as the number goes from `start` to `end`.
+ */
+export const SmoothstepExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "start",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "end",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "number",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["SmoothstepExpression"] = SmoothstepExpressionSchema;
+
+/**
+ * Generated schema.
+ * Applies an easing curve to a number from 0 to 1.
+ */
+export const EaseExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "curve",
+      "ty": {
+        "type": "Reference",
+        "content": "EasingCurve"
+      },
+      "docs": null
+    },
+    {
+      "name": "number",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["EaseExpression"] = EaseExpressionSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * A standard easing curve, mapping 0-1 to 0-1.
+ */
+export const EasingCurveSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Linear",
+        "value": null
+      },
+      {
+        "name": "InQuad",
+        "value": null
+      },
+      {
+        "name": "OutQuad",
+        "value": null
+      },
+      {
+        "name": "InOutQuad",
+        "value": null
+      },
+      {
+        "name": "InCubic",
+        "value": null
+      },
+      {
+        "name": "OutCubic",
+        "value": null
+      },
+      {
+        "name": "InOutCubic",
+        "value": null
+      },
+      {
+        "name": "InSine",
+        "value": null
+      },
+      {
+        "name": "OutSine",
+        "value": null
+      },
+      {
+        "name": "InOutSine",
+        "value": null
+      },
+      {
+        "name": "InExpo",
+        "value": null
+      },
+      {
+        "name": "OutExpo",
+        "value": null
+      },
+      {
+        "name": "InOutExpo",
+        "value": null
+      },
+      {
+        "name": "InBack",
+        "value": null
+      },
+      {
+        "name": "OutBack",
+        "value": null
+      },
+      {
+        "name": "InOutBack",
+        "value": null
+      },
+      {
+        "name": "InBounce",
+        "value": null
+      },
+      {
+        "name": "OutBounce",
+        "value": null
+      },
+      {
+        "name": "InOutBounce",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["EasingCurve"] = EasingCurveSchema;
+
+/**
+ * Generated schema.
+ * Interpolates between keyframes, using the input as the time; usually, the input is `time`.
+ * Keyframes are sorted by time when they're loaded.
+ * Written as `keyframes(input, time, value, time, value, ...)`, or `keyframes_loop` and `keyframes_ping_pong`
+ * for the other modes, in the text form of expressions. A keyframe's easing is written by wrapping its value,
+ * like `keyframes(time, 0, 0, 5, ease_in_quad(255))`.
+ */
+export const KeyframesExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "input",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "keyframes",
+      "ty": {
+        "type": "ArrayOf",
+        "content": {
+          "type": "Reference",
+          "content": "Keyframe"
+        }
+      },
+      "docs": null
+    },
+    {
+      "name": "mode",
+      "ty": {
+        "type": "Reference",
+        "content": "KeyframeMode"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["KeyframesExpression"] = KeyframesExpressionSchema;
+
+/**
+ * Generated schema.
+ * A value at a point in time.
+ */
+export const KeyframeSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "time",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "value",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "easing",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "EasingCurve"
+        }
+      },
+      "docs": "The easing curve used to reach this keyframe's value from the previous one. Defaults to linear."
+    }
+  ]
+};
+schemas["Keyframe"] = KeyframeSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * What a keyframes expression does outside the time range of its keyframes.
+ */
+export const KeyframeModeSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Hold",
+        "value": null
+      },
+      {
+        "name": "Loop",
+        "value": null
+      },
+      {
+        "name": "PingPong",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["KeyframeMode"] = KeyframeModeSchema;
+
+/**
+ * Generated schema.
+ * Picks between two expressions: `if_true` when the condition is greater than 0, and `if_false` otherwise.
+ * Only the chosen expression is computed.
+ */
+export const SelectExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "condition",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "if_true",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "if_false",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["SelectExpression"] = SelectExpressionSchema;
+
+/**
+ * Generated schema.
+ * A random number from 0 to 1. Without an input, a new number is generated every time it's computed.
+ * With an input, the number only changes when the input crosses a whole number, so `random(1, pixel)`
+ * gives every pixel its own random value that stays the same across frames.
+ */
+export const RandomExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "seed",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "input",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "AnyExpression"
+        }
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["RandomExpression"] = RandomExpressionSchema;
+
+/**
+ * Generated schema.
+ * Smooth noise from 0 to 1 with a feature size of 1 unit. With only `x`, the noise is 1D (for example,
+ * over time); with `y` as well, it's 2D (for example, over time and the pixel's position).
+ */
+export const NoiseExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "kind",
+      "ty": {
+        "type": "Reference",
+        "content": "NoiseKind"
+      },
+      "docs": null
+    },
+    {
+      "name": "seed",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "x",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "y",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "AnyExpression"
+        }
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["NoiseExpression"] = NoiseExpressionSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * The kind of smooth noise a noise expression generates.
+ */
+export const NoiseKindSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Value",
+        "value": null
+      },
+      {
+        "name": "Perlin",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["NoiseKind"] = NoiseKindSchema;
+
+/**
+ * Generated schema.
+ * A gradient of color stops that effects and color expressions can sample by position.
+ * A plain list of colors, like `[{ "r": 255, "g": 0, "b": 0, "alpha": 1 }, ...]`, is deserialized as evenly
+ * spaced stops that are blended in RGB without wrapping.
+ */
+export const GradientSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "stops",
+      "ty": {
+        "type": "ArrayOf",
+        "content": {
+          "type": "Reference",
+          "content": "ColorStop"
+        }
+      },
+      "docs": null
+    },
+    {
+      "name": "interpolation",
+      "ty": {
+        "type": "Reference",
+        "content": "GradientInterpolation"
+      },
+      "docs": null
+    },
+    {
+      "name": "wrap",
+      "ty": {
+        "type": "Boolean"
+      },
+      "docs": "Whether positions outside 0 to 1 repeat the gradient instead of being clamped, with the last stop blending back into the first."
+    }
+  ]
+};
+schemas["Gradient"] = GradientSchema;
+
+/**
+ * Generated schema.
+ * A color at a position in a gradient.
+ */
+export const ColorStopSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "position",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The position of the stop, from 0 to 1."
+    },
+    {
+      "name": "color",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["ColorStop"] = ColorStopSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * A wrapper for any color expression.
+ * Used for serialization and deserialization.
+ * A plain color, like `{ "r": 255, "g": 0, "b": 0, "alpha": 1 }`, is deserialized as a constant.
+ */
+export const AnyColorExpressionSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Constant",
+        "value": {
+          "type": "Reference",
+          "content": "ConstantColorExpression"
+        }
+      },
+      {
+        "name": "RGB",
+        "value": {
+          "type": "Reference",
+          "content": "RGBColorExpression"
+        }
+      },
+      {
+        "name": "HSL",
+        "value": {
+          "type": "Reference",
+          "content": "HSLColorExpression"
+        }
+      },
+      {
+        "name": "Gradient",
+        "value": {
+          "type": "Reference",
+          "content": "GradientColorExpression"
+        }
+      },
+      {
+        "name": "Lerp",
+        "value": {
+          "type": "Reference",
+          "content": "LerpColorExpression"
+        }
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["AnyColorExpression"] = AnyColorExpressionSchema;
+
+/**
+ * Generated schema.
+ * A constant color.
+ */
+export const ConstantColorExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "color",
+      "ty": {
+        "type": "Reference",
+        "content": "PixelColor"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["ConstantColorExpression"] = ConstantColorExpressionSchema;
+
+/**
+ * Generated schema.
+ * A pixel is a single unit of color data with an alpha value.
+ */
+export const PixelColorSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "r",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "g",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "b",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "alpha",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["PixelColor"] = PixelColorSchema;
+
+/**
+ * Generated schema.
+ * A color from expressions for its red, green, and blue channels, from 0 to 1.
+ */
+export const RGBColorExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "red",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "green",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "blue",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "alpha",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "AnyExpression"
+        }
+      },
+      "docs": "The alpha of the color, from 0 to 1. Defaults to fully opaque."
+    }
+  ]
+};
+schemas["RGBColorExpression"] = RGBColorExpressionSchema;
+
+/**
+ * Generated schema.
+ * A color from expressions for its hue, saturation, and lightness.
+ * Hue is in degrees and wraps around; saturation and lightness are from 0 to 1.
+ */
+export const HSLColorExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "hue",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "saturation",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "lightness",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "alpha",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "AnyExpression"
+        }
+      },
+      "docs": "The alpha of the color, from 0 to 1. Defaults to fully opaque."
+    }
+  ]
+};
+schemas["HSLColorExpression"] = HSLColorExpressionSchema;
+
+/**
+ * Generated schema.
+ * Looks up a color in a gradient. With a wrapping gradient, a position that increases over time cycles through the colors forever.
+ */
+export const GradientColorExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "gradient",
+      "ty": {
+        "type": "Reference",
+        "content": "Gradient"
+      },
+      "docs": null
+    },
+    {
+      "name": "position",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["GradientColorExpression"] = GradientColorExpressionSchema;
+
+/**
+ * Generated schema.
+ * Blends between two colors. `t` is clamped from 0 to 1.
+ */
+export const LerpColorExpressionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "start",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "end",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "t",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["LerpColorExpression"] = LerpColorExpressionSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * How colors are blended between the stops of a gradient.
+ */
+export const GradientInterpolationSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "RGB",
+        "value": null
+      },
+      {
+        "name": "HSL",
+        "value": null
+      },
+      {
+        "name": "OKLab",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["GradientInterpolation"] = GradientInterpolationSchema;
+
+/**
+ * Generated schema.
+ * The music visualizer effect renders the audio data received from the
+ * music visualizer client as a visualizer.
+ */
+export const MusicVisualizerEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "gradient",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "Gradient"
+        }
+      },
+      "docs": "The colors along the strip, with the lightness set by the audio level. If not set, a rainbow is used."
+    }
+  ]
+};
+schemas["MusicVisualizerEffect"] = MusicVisualizerEffectSchema;
+
+/**
+ * Generated schema.
+ */
+export const RotateEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "effect",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyEffect"
+      },
+      "docs": "The effect to rotate"
+    },
+    {
+      "name": "rotation",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The number of pixels to rotate the frame by. If negative, it rotates to the left."
+    }
+  ]
+};
+schemas["RotateEffect"] = RotateEffectSchema;
+
+/**
+ * Generated schema.
+ */
+export const FlashingColorEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "offset",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The offset of the flashing effect, in seconds."
+    },
+    {
+      "name": "speed",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The speed of the flashing effect, in Hz."
+    },
+    {
+      "name": "color_a",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": "The color of the first flashing color."
+    },
+    {
+      "name": "color_b",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": "The color of the second flashing color."
+    }
+  ]
+};
+schemas["FlashingColorEffect"] = FlashingColorEffectSchema;
+
+/**
+ * Generated schema.
+ */
+export const SolidColorEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "color",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": "The color to make every pixel."
+    },
+    {
+      "name": "start",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The start pixel index."
+    },
+    {
+      "name": "stop",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The stop pixel index."
+    }
+  ]
+};
+schemas["SolidColorEffect"] = SolidColorEffectSchema;
+
+/**
+ * Generated schema.
+ * Fills the strip with white light at a color temperature, corrected with the installation's white calibration
+ * so it matches light bulbs of the same temperature.
+ */
+export const WhiteEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "temperature",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The color temperature, in Kelvin. Warm white bulbs are around 2700K and daylight is around 6500K."
+    },
+    {
+      "name": "brightness",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The brightness, from 0 to 1."
+    }
+  ]
+};
+schemas["WhiteEffect"] = WhiteEffectSchema;
+
+/**
+ * Generated schema.
+ */
+export const ExpressionColorEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "color",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": "The color expression computed for every pixel to get its color."
+    },
+    {
+      "name": "alpha",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "AnyExpression"
+        }
+      },
+      "docs": "The alpha of every pixel, from 0 to 1. Overrides the alpha of the color if set."
+    }
+  ]
+};
+schemas["ExpressionColorEffect"] = ExpressionColorEffectSchema;
+
+/**
+ * Generated schema.
+ * A gradient along a direction in the room, following the room's geometry instead of the strip's wiring.
+ */
+export const LinearGradientEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "gradient",
+      "ty": {
+        "type": "Reference",
+        "content": "Gradient"
+      },
+      "docs": "The colors of the gradient. Wrapping gradients repeat along the direction."
+    },
+    {
+      "name": "angle",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The direction of the gradient, in degrees. 0 points along the x axis and 90 along the y axis."
+    },
+    {
+      "name": "length",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The distance the gradient spans from position 0 to 1, in meters."
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How far the gradient is moved along its direction from the room's origin, in meters.Increasing it over time makes the gradient travel."
+    }
+  ]
+};
+schemas["LinearGradientEffect"] = LinearGradientEffectSchema;
+
+/**
+ * Generated schema.
+ * A gradient outward from a point in the room.
+ */
+export const RadialGradientEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "gradient",
+      "ty": {
+        "type": "Reference",
+        "content": "Gradient"
+      },
+      "docs": "The colors of the gradient from the center outward. Wrapping gradients repeat as rings."
+    },
+    {
+      "name": "center_x",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The center of the gradient in the room, in meters."
+    },
+    {
+      "name": "center_y",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "radius",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The distance from the center to position 1 of the gradient, in meters."
+    },
+    {
+      "name": "offset",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How far the gradient is moved outward, in meters. Increasing it over time makes rings expand from the center."
+    }
+  ]
+};
+schemas["RadialGradientEffect"] = RadialGradientEffectSchema;
+
+/**
+ * Generated schema.
+ * A sine wave that travels across the room in a straight line, blending between two colors.
+ */
+export const PlaneWaveEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "color_a",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": "The color at the troughs of the wave."
+    },
+    {
+      "name": "color_b",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": "The color at the crests of the wave."
+    },
+    {
+      "name": "angle",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The direction the wave travels in, in degrees. 0 points along the x axis and 90 along the y axis."
+    },
+    {
+      "name": "wavelength",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The distance between crests, in meters."
+    },
+    {
+      "name": "speed",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How fast the wave travels, in meters per second."
+    }
+  ]
+};
+schemas["PlaneWaveEffect"] = PlaneWaveEffectSchema;
+
+/**
+ * Generated schema.
+ * A procedural fire simulation. Heat is simulated separately along every span in the spatial map:
+ * every frame, each pixel cools down, heat drifts up away from the base, and new sparks randomly ignite near the base.
+ */
+export const FireEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "cooling",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How quickly the flames cool, from 0 to 1. Higher values make shorter flames."
+    },
+    {
+      "name": "sparking",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The chance of a new spark near the base of each flame every frame, from 0 to 1."
+    },
+    {
+      "name": "palette",
+      "ty": {
+        "type": "Reference",
+        "content": "Gradient"
+      },
+      "docs": "The colors heat is mapped to, from coldest at 0 to hottest at 1."
+    },
+    {
+      "name": "base",
+      "ty": {
+        "type": "Reference",
+        "content": "FireBase"
+      },
+      "docs": "Which end of each span the flames rise from."
+    }
+  ]
+};
+schemas["FireEffect"] = FireEffectSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * Which end of each span in the spatial map the flames rise from.
+ */
+export const FireBaseSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Start",
+        "value": null
+      },
+      {
+        "name": "End",
+        "value": null
+      },
+      {
+        "name": "Both",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["FireBase"] = FireBaseSchema;
+
+/**
+ * Generated schema.
+ * Random pixels fade in and out with colors from a palette. Pixels that aren't twinkling are transparent.
+ */
+export const TwinkleEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "density",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The average fraction of pixels twinkling at once, from 0 to 1."
+    },
+    {
+      "name": "fade_in",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How long a twinkle takes to fade in, in seconds."
+    },
+    {
+      "name": "fade_out",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How long a twinkle takes to fade out, in seconds."
+    },
+    {
+      "name": "palette",
+      "ty": {
+        "type": "Reference",
+        "content": "Gradient"
+      },
+      "docs": "The colors of the twinkles. Every twinkle picks a random position in the palette."
+    },
+    {
+      "name": "seed",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The seed for which pixels twinkle, so the effect looks the same every time it runs."
+    }
+  ]
+};
+schemas["TwinkleEffect"] = TwinkleEffectSchema;
+
+/**
+ * Generated schema.
+ * One or more comets with exponentially fading tails that chase each other along the strip.
+ */
+export const CometEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "heads",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The number of comets, evenly spaced along the path, up to 32."
+    },
+    {
+      "name": "speed",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How fast the comets move, in pixels or meters per second depending on the path. Negative speeds move backward."
+    },
+    {
+      "name": "tail_length",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "The length of each tail, in pixels or meters depending on the path."
+    },
+    {
+      "name": "colors",
+      "ty": {
+        "type": "Reference",
+        "content": "Gradient"
+      },
+      "docs": "The colors of each comet, from 0 at the head to 1 at the end of the tail."
+    },
+    {
+      "name": "mode",
+      "ty": {
+        "type": "Reference",
+        "content": "CometMode"
+      },
+      "docs": null
+    },
+    {
+      "name": "path",
+      "ty": {
+        "type": "Reference",
+        "content": "CometPath"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["CometEffect"] = CometEffectSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * What the comets do when they reach the end of their path.
+ */
+export const CometModeSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Wrap",
+        "value": null
+      },
+      {
+        "name": "Bounce",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["CometMode"] = CometModeSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * The path the comets travel along.
+ */
+export const CometPathSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Strip",
+        "value": null
+      },
+      {
+        "name": "Perimeter",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["CometPath"] = CometPathSchema;
+
+/**
+ * Generated schema.
+ * Plays an image or animated GIF on the strip.
+ */
+export const ImageEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "image",
+      "ty": {
+        "type": "String"
+      },
+      "docs": "The name of a GIF uploaded to the controller with `PUT /api/image/:name`."
+    },
+    {
+      "name": "mapping",
+      "ty": {
+        "type": "Reference",
+        "content": "ImageMapping"
+      },
+      "docs": null
+    },
+    {
+      "name": "speed",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyExpression"
+      },
+      "docs": "How fast the image plays. 1 is the speed the GIF was made for."
+    },
+    {
+      "name": "looping",
+      "ty": {
+        "type": "Boolean"
+      },
+      "docs": "Whether the image starts over once it ends. Otherwise, it stays on the last frame."
+    }
+  ]
+};
+schemas["ImageEffect"] = ImageEffectSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * How an image is mapped onto the pixels.
+ */
+export const ImageMappingSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Rows",
+        "value": {
+          "type": "Reference",
+          "content": "RowsImageMapping"
+        }
+      },
+      {
+        "name": "Project",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["ImageMapping"] = ImageMappingSchema;
+
+/**
+ * Generated schema.
+ * Shows one row of the image at a time, stretched across the strip, moving down the image over time.
+ */
+export const RowsImageMappingSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "rows_per_second",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "How many rows are shown every second."
+    }
+  ]
+};
+schemas["RowsImageMapping"] = RowsImageMappingSchema;
+
+/**
+ * Generated schema.
+ * Shows the time of day around the room like the face of a clock, with the hands placed by their angle from the center of the room.
+ * Uses the controller's local time zone.
+ */
+export const ClockEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "hour",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "ClockMarker"
+        }
+      },
+      "docs": null
+    },
+    {
+      "name": "minute",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "ClockMarker"
+        }
+      },
+      "docs": null
+    },
+    {
+      "name": "second",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "ClockMarker"
+        }
+      },
+      "docs": null
+    },
+    {
+      "name": "ticks",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "ClockMarker"
+        }
+      },
+      "docs": "Markers at every hour, drawn under the hands."
+    },
+    {
+      "name": "rotation",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The direction of 12 o'clock from the center of the room, in degrees. 0 points along the x axis and 90 along the y axis."
+    },
+    {
+      "name": "clockwise",
+      "ty": {
+        "type": "Boolean"
+      },
+      "docs": "Whether the hands move clockwise when looking down at the room with the y axis pointing up."
+    }
+  ]
+};
+schemas["ClockEffect"] = ClockEffectSchema;
+
+/**
+ * Generated schema.
+ * A marker on the clock, like a hand or the hour ticks.
+ */
+export const ClockMarkerSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "color",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": null
+    },
+    {
+      "name": "width",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The width of the marker around the room, in degrees. Markers fade out toward their edges."
+    }
+  ]
+};
+schemas["ClockMarker"] = ClockMarkerSchema;
+
+/**
+ * Generated schema.
+ */
+export const WebsocketInputEffectSchema: Schema = {
+  "type": "Struct",
+  "content": []
+};
+schemas["WebsocketInputEffect"] = WebsocketInputEffectSchema;
+
+/**
+ * Generated schema.
+ * An effect that renders a frame based on a node-based graphical editor.
+ * This is by far the most complex effect type, as it allows for arbitrary
+ * calculations for every pixel in the frame.
+ */
+export const NodeEditorEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "nodes",
+      "ty": {
+        "type": "Reference",
+        "content": "NodeData"
+      },
+      "docs": null
+    },
+    {
+      "name": "output",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "NodeConnection"
+        }
+      },
+      "docs": "The node output that is displayed. A color output is evaluated for every pixel, while a frame output is used directly."
+    }
+  ]
+};
+schemas["NodeEditorEffect"] = NodeEditorEffectSchema;
+
+/**
+ * Generated schema.
+ */
+export const NodeDataSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "type",
+      "ty": {
+        "type": "String"
+      },
+      "docs": null
+    },
+    {
+      "name": "inputs",
+      "ty": {
+        "type": "ArrayOf",
+        "content": {
+          "type": "Optional",
+          "content": {
+            "type": "Reference",
+            "content": "NodeConnection"
+          }
+        }
+      },
+      "docs": null
+    },
+    {
+      "name": "parameters",
+      "ty": {
+        "type": "Reference",
+        "content": "NodeParameter"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["NodeData"] = NodeDataSchema;
+
+/**
+ * Generated schema.
+ * A connection from an output port of another node.
+ */
+export const NodeConnectionSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "node",
+      "ty": {
+        "type": "Reference",
+        "content": "NodeID"
+      },
+      "docs": "The node the value comes from."
+    },
+    {
+      "name": "output",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The index of the output port on that node."
+    }
+  ]
+};
+schemas["NodeConnection"] = NodeConnectionSchema;
+
+/**
+ * Generated schema.
  * Tagged with "type".
+ * A value configured in the editor rather than connected from another node.
  */
-export const ValueSchema: Schema = {
+export const NodeParameterSchema: Schema = {
   "type": "Enum",
   "content": {
     "variants": [
@@ -766,9 +2621,21 @@ export const ValueSchema: Schema = {
         }
       },
       {
-        "name": "Integer",
+        "name": "Int",
+        "value": {
+          "type": "Number"
+        }
+      },
+      {
+        "name": "Bool",
         "value": {
-          "type": "Number"
+          "type": "Boolean"
+        }
+      },
+      {
+        "name": "String",
+        "value": {
+          "type": "String"
         }
       },
       {
@@ -779,44 +2646,364 @@ export const ValueSchema: Schema = {
         }
       },
       {
-        "name": "Boolean",
+        "name": "Effect",
         "value": {
-          "type": "Boolean"
+          "type": "Reference",
+          "content": "AnyEffect"
         }
       },
       {
-        "name": "Frame",
+        "name": "Type",
+        "value": {
+          "type": "Reference",
+          "content": "TypeInfo"
+        }
+      },
+      {
+        "name": "Group",
         "value": {
           "type": "Reference",
-          "content": "Frame"
+          "content": "NodeGroup"
         }
       }
     ],
     "tag_name": "type",
+    "content_subfield": "value"
+  }
+};
+schemas["NodeParameter"] = NodeParameterSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ */
+export const TypeInfoSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "Int",
+        "value": null
+      },
+      {
+        "name": "Float",
+        "value": null
+      },
+      {
+        "name": "Bool",
+        "value": null
+      },
+      {
+        "name": "String",
+        "value": null
+      },
+      {
+        "name": "Color",
+        "value": null
+      },
+      {
+        "name": "Frame",
+        "value": null
+      }
+    ],
+    "tag_name": "type",
     "content_subfield": null
   }
 };
-schemas["Value"] = ValueSchema;
+schemas["TypeInfo"] = TypeInfoSchema;
 
 /**
  * Generated schema.
- * A frame is a single set of pixel data.
+ * A reusable subgraph that is used like a single node.
+ * Groups are saved in a library alongside the effect presets, and every group node holds its own copy of the group.
  */
-export const FrameSchema: Schema = {
+export const NodeGroupSchema: Schema = {
   "type": "Struct",
   "content": [
     {
-      "name": "pixel_data",
+      "name": "inputs",
       "ty": {
         "type": "ArrayOf",
         "content": {
           "type": "Reference",
-          "content": "PixelColor"
+          "content": "PortInfo"
+        }
+      },
+      "docs": "The inputs exposed by the group. Group input nodes inside the group read these by index."
+    },
+    {
+      "name": "outputs",
+      "ty": {
+        "type": "ArrayOf",
+        "content": {
+          "type": "Reference",
+          "content": "GroupOutput"
+        }
+      },
+      "docs": "The outputs exposed by the group."
+    },
+    {
+      "name": "nodes",
+      "ty": {
+        "type": "Reference",
+        "content": "NodeData"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["NodeGroup"] = NodeGroupSchema;
+
+/**
+ * Generated schema.
+ */
+export const PortInfoSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "name",
+      "ty": {
+        "type": "String"
+      },
+      "docs": null
+    },
+    {
+      "name": "type_info",
+      "ty": {
+        "type": "Reference",
+        "content": "TypeInfo"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["PortInfo"] = PortInfoSchema;
+
+/**
+ * Generated schema.
+ * An output exposed by a node group.
+ */
+export const GroupOutputSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "name",
+      "ty": {
+        "type": "String"
+      },
+      "docs": null
+    },
+    {
+      "name": "type_info",
+      "ty": {
+        "type": "Reference",
+        "content": "TypeInfo"
+      },
+      "docs": null
+    },
+    {
+      "name": "source",
+      "ty": {
+        "type": "Optional",
+        "content": {
+          "type": "Reference",
+          "content": "NodeConnection"
+        }
+      },
+      "docs": "The node output inside the group that provides the value. Unconnected outputs use a default value."
+    }
+  ]
+};
+schemas["GroupOutput"] = GroupOutputSchema;
+
+/**
+ * Generated schema.
+ * Tagged with "type".
+ * A wrapper for any temporary effect that can be rendered.
+ * Used for serialization and deserialization.
+ */
+export const AnyTemporaryEffectSchema: Schema = {
+  "type": "Enum",
+  "content": {
+    "variants": [
+      {
+        "name": "TemporaryEffectWrapper",
+        "value": {
+          "type": "Reference",
+          "content": "DurationTemporaryEffect"
+        }
+      },
+      {
+        "name": "Ripple",
+        "value": {
+          "type": "Reference",
+          "content": "RippleTemporaryEffect"
+        }
+      },
+      {
+        "name": "Sunrise",
+        "value": {
+          "type": "Reference",
+          "content": "SunriseTemporaryEffect"
         }
+      }
+    ],
+    "tag_name": "type",
+    "content_subfield": null
+  }
+};
+schemas["AnyTemporaryEffect"] = AnyTemporaryEffectSchema;
+
+/**
+ * Generated schema.
+ */
+export const DurationTemporaryEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "duration",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    },
+    {
+      "name": "effect",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyEffect"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["DurationTemporaryEffect"] = DurationTemporaryEffectSchema;
+
+/**
+ * Generated schema.
+ * Rings of color that expand from a point in the room and fade out as they reach the farthest pixel.
+ * Finishes once the last ring has faded out.
+ */
+export const RippleTemporaryEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "origin",
+      "ty": {
+        "type": "Reference",
+        "content": "Location"
+      },
+      "docs": "The point the rings expand from."
+    },
+    {
+      "name": "color",
+      "ty": {
+        "type": "Reference",
+        "content": "AnyColorExpression"
+      },
+      "docs": "The color of the rings."
+    },
+    {
+      "name": "speed",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "How fast the rings expand, in meters per second."
+    },
+    {
+      "name": "width",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The width of each ring, in meters."
+    },
+    {
+      "name": "rings",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The number of rings, up to 16."
+    },
+    {
+      "name": "interval",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The time between rings, in seconds."
+    }
+  ]
+};
+schemas["RippleTemporaryEffect"] = RippleTemporaryEffectSchema;
+
+/**
+ * Generated schema.
+ * A 2D location, with x and y coordinates in meters.
+ */
+export const LocationSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "x",
+      "ty": {
+        "type": "Number"
       },
       "docs": null
+    },
+    {
+      "name": "y",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": null
+    }
+  ]
+};
+schemas["Location"] = LocationSchema;
+
+/**
+ * Generated schema.
+ * Fades the whole strip in from dark, warm light to bright, cool light, like a sunrise. Overrides everything under it.
+ * Once the sunrise is over, it stays at full brightness for a while before finishing, unless it's dismissed first.
+ */
+export const SunriseTemporaryEffectSchema: Schema = {
+  "type": "Struct",
+  "content": [
+    {
+      "name": "duration",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "How long the sunrise takes, in seconds."
+    },
+    {
+      "name": "hold",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "How long the light stays on after the sunrise, in seconds."
+    },
+    {
+      "name": "start_temperature",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The color temperature at the start of the sunrise, in Kelvin."
+    },
+    {
+      "name": "end_temperature",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The color temperature at the end of the sunrise, in Kelvin."
+    },
+    {
+      "name": "brightness",
+      "ty": {
+        "type": "Number"
+      },
+      "docs": "The brightness at the end of the sunrise, from 0 to 1."
     }
   ]
 };
-schemas["Frame"] = FrameSchema;
+schemas["SunriseTemporaryEffect"] = SunriseTemporaryEffectSchema;
 
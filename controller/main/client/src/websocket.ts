@@ -1,4 +1,4 @@
-import type { LightPosition, ServerToClientMessage, StatusUpdateMessage, SystemStatusUpdateMessage, EffectPreset } from "@shared-bindings/index";
+import type { LightPosition, ServerToClientMessage, ClientToServerMessage, StatusUpdateMessage, SystemStatusUpdateMessage, EffectPreset } from "@shared-bindings/index";
 import { writable } from "svelte/store";
 
 const websocket = new WebSocket(`${window.location.protocol.startsWith("https") ? "wss" : "ws"}://${window.location.host}/websocket`);
@@ -86,6 +86,13 @@ export function sendLightData(data: Uint8Array) {
     websocket.send(data);
 }
 
+// Set a variable that expressions can read with `$name`.
+// Changes are smoothed by the server, so this can be called for every slider movement.
+export function setVariable(name: string, value: number) {
+    const message: ClientToServerMessage = { type: "SetVariable", name, value };
+    websocket.send(JSON.stringify(message));
+}
+
 function updateStatus() {
     const data = currentData;
 
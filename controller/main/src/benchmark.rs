@@ -0,0 +1,264 @@
+use std::{collections::HashMap, time::{Duration, Instant}};
+
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::{render::{effects::{self, AnyEffect, Effect, RenderContext}, expressions::{self, AnyExpression, Expression}, frame::PixelColor, RenderInfo}, TOTAL_PIXELS};
+
+/// The output is limited to around 40 FPS, so rendering a frame must take well under this.
+static FRAME_BUDGET: Duration = Duration::from_millis(25);
+
+static WARMUP_FRAMES: u32 = 20;
+static BENCHMARK_FRAMES: u32 = 500;
+
+/// Results are saved here with `--save-baseline`, and later runs are compared against them.
+/// Baselines should be saved on the device the controller runs on, since timings vary a lot between machines.
+static BASELINE_FILE: &str = "benchmark_baseline.json";
+/// How much slower than the baseline a benchmark can get before it's reported as a regression.
+/// Medians are compared since they're less affected by other processes, but very fast benchmarks still
+/// vary a lot in relative terms, so the slowdown also has to be at least `MIN_REGRESSION`.
+static REGRESSION_THRESHOLD: f64 = 0.15;
+static MIN_REGRESSION: Duration = Duration::from_micros(20);
+
+/// Renders a set of representative effects and computes a set of expressions for every pixel,
+/// printing how long each frame takes. Returns false if any benchmark regressed compared to the saved baseline.
+/// Run with `lights-controller --benchmark`; it should be built in release mode on the target device.
+pub fn run(save_baseline: bool) -> bool {
+    println!("Rendering {} frames of {} pixels per benchmark; the frame budget is {:?}", BENCHMARK_FRAMES, TOTAL_PIXELS, FRAME_BUDGET);
+
+    let baseline: Option<HashMap<String, f64>> = std::fs::read_to_string(BASELINE_FILE).ok()
+        .and_then(|baseline| serde_json::from_str(&baseline).ok());
+    let mut results = HashMap::new();
+    let mut regressions = 0;
+
+    let benchmarks = effect_benchmarks().into_iter()
+        .map(|(name, effect)| (name.to_string(), Benchmark::Effect(Box::new(effect))))
+        .chain(expression_benchmarks());
+    for (name, benchmark) in benchmarks {
+        let median = benchmark.run(&name);
+
+        let milliseconds = median.as_secs_f64() * 1000.;
+        if let Some(baseline) = baseline.as_ref().and_then(|baseline| baseline.get(&name)) {
+            let change = milliseconds / baseline - 1.;
+            println!("    Median {:+.1}% compared to the baseline ({:.3} ms)", change * 100., baseline);
+            if change > REGRESSION_THRESHOLD && milliseconds - baseline > MIN_REGRESSION.as_secs_f64() * 1000. {
+                println!("    Regression: more than {:.0}% slower than the baseline", REGRESSION_THRESHOLD * 100.);
+                regressions += 1;
+            }
+        }
+        results.insert(name, milliseconds);
+    }
+
+    if save_baseline {
+        let json = serde_json::to_string_pretty(&results).expect("Failed to serialize benchmark results");
+        std::fs::write(BASELINE_FILE, json).expect("Failed to save the benchmark baseline");
+        println!("Saved the results as the baseline in {}", BASELINE_FILE);
+    } else if baseline.is_none() {
+        println!("No baseline found; run with --save-baseline to compare future runs against these results");
+    } else if regressions > 0 {
+        println!("{} benchmark{} regressed", regressions, if regressions == 1 { "" } else { "s" });
+    }
+
+    regressions == 0
+}
+
+enum Benchmark {
+    Effect(Box<AnyEffect>),
+    /// An expression computed for every pixel
+    Expression(AnyExpression)
+}
+
+impl Benchmark {
+    /// Runs the benchmark, printing its frame times and returning the median.
+    fn run(self, name: &str) -> Duration {
+        match self {
+            Benchmark::Effect(mut effect) => {
+                // Effects are optimized when they're loaded for rendering
+                effect.optimize();
+                measure(name, |context, render_info| {
+                    std::hint::black_box(effect.render(context, render_info));
+                })
+            }
+            Benchmark::Expression(mut expression) => measure(name, |context, render_info| {
+                let mut total = 0.;
+                for pixel in 0..context.pixels {
+                    total += expression.compute(&context.pixel_expression_context(pixel, render_info));
+                }
+                std::hint::black_box(total);
+            })
+        }
+    }
+}
+
+fn effect_benchmarks() -> Vec<(&'static str, AnyEffect)> {
+    let stripes = effects::StripeEffect::new(TOTAL_PIXELS as f64 / 28., vec![
+        (255, 0, 0).into(),
+        (0, 255, 0).into(),
+        (0, 0, 255).into(),
+    ]);
+
+    vec![
+        ("Stripes effect", stripes.clone()),
+        ("Rotated stripes effect", effects::RotateEffect::new(
+            Box::new(stripes.clone()),
+            expressions::parse("time * 84").unwrap()
+        )),
+        ("Expression color effect: rainbow", serde_json::from_value(json!({
+            "type": "ExpressionColor",
+            "color": { "type": "HSL", "hue": "position * 360 + time * 30", "saturation": "1", "lightness": "0.5" }
+        })).expect("Benchmark effects should be valid")),
+        ("Expression color effect: gradient", serde_json::from_value(json!({
+            "type": "ExpressionColor",
+            "color": {
+                "type": "Gradient",
+                "gradient": {
+                    "stops": [
+                        { "position": 0.0, "color": PixelColor::new(255, 0, 0, 1.0) },
+                        { "position": 0.3, "color": PixelColor::new(255, 200, 0, 1.0) },
+                        { "position": 0.7, "color": PixelColor::new(0, 80, 255, 1.0) }
+                    ],
+                    "interpolation": { "type": "OKLab" },
+                    "wrap": true
+                },
+                "position": "position + time * 0.1"
+            }
+        })).expect("Benchmark effects should be valid")),
+        // Stops that change over time can't be computed ahead of time
+        ("Expression color effect: animated gradient", serde_json::from_value(json!({
+            "type": "ExpressionColor",
+            "color": {
+                "type": "Gradient",
+                "gradient": {
+                    "stops": [
+                        { "position": 0.0, "color": { "type": "HSL", "hue": "time * 30", "saturation": "1", "lightness": "0.5" } },
+                        { "position": 0.5, "color": PixelColor::new(255, 200, 0, 1.0) },
+                        { "position": 1.0, "color": { "type": "HSL", "hue": "time * 30 + 180", "saturation": "1", "lightness": "0.5" } }
+                    ],
+                    "interpolation": { "type": "RGB" },
+                    "wrap": false
+                },
+                "position": "position"
+            }
+        })).expect("Benchmark effects should be valid")),
+        // A rainbow computed for every pixel: hue = position + time * 0.1
+        ("Node graph: per-pixel rainbow", node_graph(&[
+            ("PixelNode", vec![], json!({})),
+            ("TimeNode", vec![], json!({})),
+            ("FloatNode", vec![], json!({ "value": { "type": "Float", "value": 0.1 } })),
+            ("MultiplyNode", vec![Some((1, 0)), Some((2, 0))], json!({})),
+            ("AddNode", vec![Some((0, 1)), Some((3, 0))], json!({})),
+            ("FloatNode", vec![], json!({ "value": { "type": "Float", "value": 1.0 } })),
+            ("FloatNode", vec![], json!({ "value": { "type": "Float", "value": 0.5 } })),
+            ("CombineHSLNode", vec![Some((4, 0)), Some((5, 0)), Some((6, 0))], json!({})),
+        ])),
+        // An effect rendered once per frame and sampled with a moving offset for every pixel
+        ("Node graph: sampled effect", node_graph(&[
+            ("EffectNode", vec![], json!({ "effect": { "type": "Effect", "value": stripes } })),
+            ("PixelNode", vec![], json!({})),
+            ("TimeNode", vec![], json!({})),
+            ("FloatNode", vec![], json!({ "value": { "type": "Float", "value": 84.0 } })),
+            ("MultiplyNode", vec![Some((2, 0)), Some((3, 0))], json!({})),
+            ("IntAddNode", vec![Some((1, 0)), Some((4, 0))], json!({})),
+            ("SampleFrameNode", vec![Some((0, 0)), Some((5, 0))], json!({})),
+        ])),
+        // Per-pixel state: random values smoothed separately for every pixel
+        ("Node graph: per-pixel smoothed noise", node_graph(&[
+            ("PixelNode", vec![], json!({})),
+            ("RandomNode", vec![Some((0, 1))], json!({})),
+            ("FloatNode", vec![], json!({ "value": { "type": "Float", "value": 0.5 } })),
+            ("SmoothNode", vec![Some((1, 0)), Some((2, 0))], json!({})),
+            ("ColorNode", vec![], json!({ "value": { "type": "Color", "value": PixelColor::new(255, 80, 0, 1.0) } })),
+            ("ColorNode", vec![], json!({ "value": { "type": "Color", "value": PixelColor::BLACK } })),
+            ("LerpColorNode", vec![Some((5, 0)), Some((4, 0)), Some((3, 0))], json!({})),
+        ])),
+    ]
+}
+
+/// Expressions computed for every pixel, both as written and with constants folded.
+fn expression_benchmarks() -> Vec<(String, Benchmark)> {
+    let expressions = [
+        ("arithmetic", "position * 360 + time * 30 - pixel % 7"),
+        ("constant subtrees", "sin(time * (2 * pi / 5)) * (255 / 2) + 255 / 2 * cos(pi / 4)"),
+        ("easing and select", "select(sin(time), ease_in_out_cubic(position), 1 - ease_out_bounce(position))"),
+        ("variables and audio", "time * $speed + bass * 2 + beat"),
+        ("2D Perlin noise", "perlin(1, time * 0.5, position * 20)"),
+    ];
+
+    expressions.iter().flat_map(|(name, text)| {
+        let expression = expressions::parse(text).expect("Benchmark expressions should be valid");
+        let mut folded = expression.clone();
+        folded.fold_constants();
+        [
+            (format!("Expression: {}", name), Benchmark::Expression(expression)),
+            (format!("Expression: {} (folded)", name), Benchmark::Expression(folded))
+        ]
+    }).collect()
+}
+
+/// A node's type, inputs and parameters. Inputs connect to earlier nodes with (node index, output index) pairs.
+type BenchmarkNode<'a> = (&'a str, Vec<Option<(usize, u32)>>, Value);
+
+/// Builds a node editor effect from a list of nodes. The first output of the last node is displayed.
+fn node_graph(nodes: &[BenchmarkNode]) -> AnyEffect {
+    let ids = nodes.iter().map(|_| Uuid::new_v4().to_string()).collect::<Vec<_>>();
+    let graph_nodes = nodes.iter().zip(&ids).map(|((node_type, inputs, parameters), id)| {
+        let inputs = inputs.iter().map(|input| input.map(|(node, output)| json!({
+            "node": ids[node],
+            "output": output
+        }))).collect::<Vec<_>>();
+
+        (id.clone(), json!({
+            "type": node_type,
+            "inputs": inputs,
+            "parameters": parameters
+        }))
+    }).collect::<serde_json::Map<_, _>>();
+
+    serde_json::from_value(json!({
+        "type": "NodeEditorEffect",
+        "nodes": graph_nodes,
+        "output": { "node": ids.last().unwrap(), "output": 0 }
+    })).expect("Benchmark node graphs should be valid")
+}
+
+/// Times a frame function over the benchmark frames, printing the frame times and returning the median.
+fn measure(name: &str, mut frame_function: impl FnMut(RenderContext, &mut RenderInfo)) -> Duration {
+    let mut render_info = RenderInfo::new(&crate::spatial_map());
+    let delta = FRAME_BUDGET;
+
+    let mut frame_times = Vec::with_capacity(BENCHMARK_FRAMES as usize);
+    for frame in 0..WARMUP_FRAMES + BENCHMARK_FRAMES {
+        render_info.frames += 1;
+        let context = RenderContext {
+            delta,
+            time: frame as f64 * delta.as_secs_f64(),
+            pixels: TOTAL_PIXELS
+        };
+
+        let start = Instant::now();
+        frame_function(context, &mut render_info);
+        let elapsed = start.elapsed();
+
+        if frame >= WARMUP_FRAMES {
+            frame_times.push(elapsed);
+        }
+    }
+
+    if !render_info.debug_text.is_empty() {
+        println!("{}: {}", name, render_info.debug_text);
+    }
+
+    let average = frame_times.iter().sum::<Duration>() / frame_times.len() as u32;
+    frame_times.sort();
+    let median = frame_times[frame_times.len() / 2];
+    let max = frame_times.last().unwrap();
+    println!(
+        "{}: average {:.3} ms, median {:.3} ms, max {:.3} ms ({:.1}% of the frame budget)",
+        name,
+        average.as_secs_f64() * 1000.,
+        median.as_secs_f64() * 1000.,
+        max.as_secs_f64() * 1000.,
+        average.as_secs_f64() / FRAME_BUDGET.as_secs_f64() * 100.
+    );
+    median
+}
@@ -16,6 +16,7 @@ use crate::{LightingState, FRAME_TIMES_STORED};
 static WEB_SERVER_PORT: u16 = shared::constants::API_PORT;
 
 pub mod presets;
+pub mod alarms;
 pub mod api;
 
 pub async fn serve(lighting_state: Arc<LightingState>) {
@@ -122,13 +123,13 @@ async fn websocket(stream: WebSocket, state: Arc<LightingState>) {
 }
 
 // Attempts to deserialize into a ClientToServerMessage and handle it
-async fn handle_client_message(message: String, _state: &Arc<LightingState>) {
+async fn handle_client_message(message: String, state: &Arc<LightingState>) {
     let deserialized_message: Result<shared::ClientToServerMessage, _> = serde_json::from_str(&message);
     if let Ok(message) = deserialized_message {
         match message {
-            // There are no messages to handle yet
-            #[allow(unreachable_patterns)]
-            _ => ()
+            shared::ClientToServerMessage::SetVariable(variable) => {
+                state.render_state.lock().info.variables.set(variable.name, variable.value);
+            }
         }
     } else {
         println!("Received invalid message: {}", message);
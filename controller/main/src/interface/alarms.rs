@@ -0,0 +1,262 @@
+use std::{io::{Error, ErrorKind}, path::PathBuf, sync::Arc, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{render::{effects::SunriseTemporaryEffect, local_time::LocalTime}, LightingState};
+
+static ALARM_FILE: &str = "alarms.json";
+
+static SECONDS_PER_WEEK: f64 = 7. * 86400.;
+/// How far the clock can go backward and still be treated as a repeated time, like when daylight saving time ends.
+/// Larger jumps are treated as the clock being corrected, so alarms aren't held off for that long.
+static MAX_REPEATED_TIME: f64 = 86400.;
+
+/// A wake-up alarm. A sunrise starts early enough to reach full brightness at the alarm time.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Alarm {
+    #[serde(default = "Uuid::new_v4")]
+    pub id: Uuid,
+    pub name: String,
+    pub enabled: bool,
+    /// The time of the alarm in the controller's time zone.
+    pub hour: u32,
+    pub minute: u32,
+    /// The days of the week the alarm goes off, starting on Sunday.
+    pub days: [bool; 7],
+    pub sunrise: SunriseTemporaryEffect
+}
+
+impl Alarm {
+    fn validate(&self) -> Result<(), Error> {
+        if self.hour >= 24 || self.minute >= 60 {
+            return Err(Error::new(ErrorKind::InvalidInput, "Invalid alarm time"));
+        }
+        Ok(())
+    }
+
+    /// The times the sunrise should start, in seconds since the start of the week.
+    fn start_times(&self) -> impl Iterator<Item = f64> + '_ {
+        let alarm_time = (self.hour * 3600 + self.minute * 60) as f64;
+        (0..7).filter(|day| self.days[*day]).map(move |day| {
+            (day as f64 * 86400. + alarm_time - self.sunrise.duration.max(0.)).rem_euclid(SECONDS_PER_WEEK)
+        })
+    }
+}
+
+/// Stores the wake-up alarms and persists them to disk.
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct Alarms {
+    alarms: Vec<Alarm>
+}
+
+impl Alarms {
+    pub fn load() -> Self {
+        match std::fs::File::open(Alarms::get_file_path()) {
+            Ok(file) => match serde_json::from_reader(file) {
+                Ok(alarms) => alarms,
+                Err(e) => {
+                    println!("Failed to load alarms from file: {}; starting with no alarms", e);
+                    Alarms::default()
+                }
+            },
+            Err(_) => Alarms::default()
+        }
+    }
+
+    pub fn get_alarms(&self) -> &[Alarm] {
+        &self.alarms
+    }
+
+    pub fn add_alarm(&mut self, mut alarm: Alarm) -> Result<(), Error> {
+        alarm.validate()?;
+        alarm.id = Uuid::new_v4();
+        self.alarms.push(alarm);
+        self.save()
+    }
+
+    pub fn update_alarm(&mut self, id: Uuid, mut alarm: Alarm) -> Result<(), Error> {
+        alarm.validate()?;
+        let existing = self.alarms.iter_mut().find(|alarm| alarm.id == id)
+            .ok_or(Error::new(ErrorKind::NotFound, "Alarm not found"))?;
+        alarm.id = id;
+        *existing = alarm;
+        self.save()
+    }
+
+    pub fn remove_alarm(&mut self, id: Uuid) -> Result<(), Error> {
+        let index = self.alarms.iter().position(|alarm| alarm.id == id)
+            .ok_or(Error::new(ErrorKind::NotFound, "Alarm not found"))?;
+        self.alarms.remove(index);
+        self.save()
+    }
+
+    /// Gets the sunrises of enabled alarms that should have started between two times, in seconds since the start of the week.
+    fn get_due_sunrises(&self, from: f64, to: f64) -> Vec<SunriseTemporaryEffect> {
+        let is_due = |time: f64| if went_backward(from, to) {
+            false
+        } else if from <= to {
+            time > from && time <= to
+        } else {
+            // The week wrapped around
+            time > from || time <= to
+        };
+        self.alarms.iter()
+            .filter(|alarm| alarm.enabled && alarm.start_times().any(is_due))
+            .map(|alarm| alarm.sunrise.clone())
+            .collect()
+    }
+
+    /// Gets the sunrises that are due when the scheduler checks the alarms, and the time to check from next time.
+    /// If the clock went back by up to `MAX_REPEATED_TIME`, the repeated time was already checked, so the scheduler
+    /// keeps checking from the last time and alarms in it don't go off twice.
+    fn check(&self, last_checked: f64, now: f64) -> (Vec<SunriseTemporaryEffect>, f64) {
+        if went_backward(last_checked, now) && last_checked - now <= MAX_REPEATED_TIME {
+            return (vec![], last_checked);
+        }
+        (self.get_due_sunrises(last_checked, now), now)
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        let file = std::fs::File::create(Alarms::get_file_path())?;
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    fn get_file_path() -> PathBuf {
+        dirs::data_dir().unwrap().join(ALARM_FILE)
+    }
+}
+
+/// Whether the clock went backward between two times in seconds since the start of the week, like when
+/// daylight saving time ends, rather than wrapping around to the next week.
+fn went_backward(from: f64, to: f64) -> bool {
+    from > to && from - to <= SECONDS_PER_WEEK / 2.
+}
+
+/// Checks the alarms every second and starts their sunrises on the render state's `alarm_effect_compositor`.
+/// It runs separately from the other temporary effects, so sunrises start on time.
+pub async fn run_scheduler(lighting_state: Arc<LightingState>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(1));
+    let mut last_checked = LocalTime::now().seconds_in_week();
+
+    loop {
+        interval.tick().await;
+
+        let now = LocalTime::now().seconds_in_week();
+        let (sunrises, next_checked) = lighting_state.alarms.read().await.check(last_checked, now);
+        last_checked = next_checked;
+
+        if !sunrises.is_empty() {
+            let mut render_state = lighting_state.render_state.lock();
+            for sunrise in sunrises {
+                println!("Starting an alarm sunrise");
+                render_state.alarm_effect_compositor.add_effect(sunrise.into());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: f64 = 3600.;
+    const DAY: f64 = 86400.;
+    const SUNDAY: usize = 0;
+    const MONDAY: usize = 1;
+
+    /// An alarm on one day of the week with a 10 minute sunrise.
+    fn alarms(day: usize, hour: u32, minute: u32) -> Alarms {
+        let sunrise = serde_json::from_str(r#"{ "duration": 600, "hold": 60, "start_temperature": 2000, "end_temperature": 5000, "brightness": 1 }"#).unwrap();
+        let mut days = [false; 7];
+        days[day] = true;
+        Alarms {
+            alarms: vec![Alarm { id: Uuid::new_v4(), name: "Alarm".to_string(), enabled: true, hour, minute, days, sunrise }]
+        }
+    }
+
+    /// Checks the alarms at each time like the scheduler, and counts how many sunrises start.
+    fn count_sunrises(alarms: &Alarms, times: impl IntoIterator<Item = f64>) -> usize {
+        let mut times = times.into_iter();
+        let mut last_checked = times.next().unwrap();
+        let mut count = 0;
+        for now in times {
+            let (sunrises, next_checked) = alarms.check(last_checked, now);
+            count += sunrises.len();
+            last_checked = next_checked;
+        }
+        count
+    }
+
+    /// Times every second from `start` up to, but not including, `end`.
+    fn seconds(start: f64, end: f64) -> impl Iterator<Item = f64> {
+        (0..(end - start) as usize).map(move |second| start + second as f64)
+    }
+
+    #[test]
+    fn starts_sunrises_before_the_alarm() {
+        let alarms = alarms(MONDAY, 7, 10);
+        let start = MONDAY as f64 * DAY + 7. * HOUR;
+        assert_eq!(count_sunrises(&alarms, [start - 1., start]), 1);
+        assert_eq!(count_sunrises(&alarms, [start - 2., start - 1.]), 0);
+        assert_eq!(count_sunrises(&alarms, [start, start + 1.]), 0);
+        assert_eq!(count_sunrises(&alarms, seconds(start - HOUR, start + HOUR)), 1);
+        // Other days don't go off
+        assert_eq!(count_sunrises(&alarms, [start + DAY - 1., start + DAY]), 0);
+    }
+
+    #[test]
+    fn starts_sunrises_when_the_week_wraps_around() {
+        let alarms = alarms(SUNDAY, 0, 20);
+        // Saturday night to Sunday morning
+        assert_eq!(count_sunrises(&alarms, [SECONDS_PER_WEEK - 1., 0., 600.]), 1);
+        assert_eq!(count_sunrises(&alarms, [SECONDS_PER_WEEK - 1., 601.]), 1);
+        assert_eq!(count_sunrises(&alarms, [SECONDS_PER_WEEK - 1., 0., 599.]), 0);
+    }
+
+    #[test]
+    fn starts_sunrises_in_hours_skipped_by_daylight_saving_time() {
+        // The clock jumps from 2:00 to 3:00, so the sunrise at 2:30 is never reached exactly
+        let alarms = alarms(MONDAY, 2, 40);
+        let monday = MONDAY as f64 * DAY;
+        let times = seconds(monday + HOUR, monday + 2. * HOUR).chain(seconds(monday + 3. * HOUR, monday + 4. * HOUR));
+        assert_eq!(count_sunrises(&alarms, times), 1);
+    }
+
+    #[test]
+    fn doesnt_repeat_sunrises_in_hours_repeated_by_daylight_saving_time() {
+        // The clock goes back from 2:00 to 1:00, so the sunrise at 1:30 is reached twice
+        let alarms = alarms(MONDAY, 1, 40);
+        let monday = MONDAY as f64 * DAY;
+        let times = seconds(monday + HOUR, monday + 2. * HOUR).chain(seconds(monday + HOUR, monday + 3. * HOUR));
+        assert_eq!(count_sunrises(&alarms, times), 1);
+    }
+
+    #[test]
+    fn doesnt_hold_off_alarms_after_the_clock_is_corrected_back() {
+        // The clock is corrected from Wednesday back to Monday, which is more than a repeated time
+        let alarms = alarms(MONDAY, 12, 10);
+        let start = MONDAY as f64 * DAY + 12. * HOUR;
+        let wednesday = start + 2. * DAY;
+        assert!(wednesday - (start - 60.) > MAX_REPEATED_TIME);
+        assert_eq!(count_sunrises(&alarms, [wednesday, start - 60., start]), 1);
+        // The correction itself doesn't start sunrises it crosses
+        assert_eq!(count_sunrises(&alarms, [wednesday, start - 60.]), 0);
+    }
+
+    #[test]
+    fn starts_sunrises_on_the_day_before_early_alarms() {
+        // A sunrise for 0:05 on Monday starts at 23:55 on Sunday
+        let alarms_monday = alarms(MONDAY, 0, 5);
+        let start = MONDAY as f64 * DAY - 300.;
+        assert_eq!(count_sunrises(&alarms_monday, [start - 1., start]), 1);
+        assert_eq!(count_sunrises(&alarms_monday, seconds(start + 1., start + HOUR)), 0);
+
+        // A sunrise for 0:05 on Sunday starts at 23:55 on Saturday, at the end of the week
+        let alarms_sunday = alarms(SUNDAY, 0, 5);
+        let start = SECONDS_PER_WEEK - 300.;
+        assert_eq!(count_sunrises(&alarms_sunday, [start - 1., start]), 1);
+        assert_eq!(count_sunrises(&alarms_sunday, seconds(start - HOUR, start + HOUR).map(|time| time.rem_euclid(SECONDS_PER_WEEK))), 1);
+    }
+}
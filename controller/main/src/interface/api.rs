@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
-use axum::{extract::{Path, Query, State}, response::IntoResponse, routing::{delete, get, post, put}, Json, Router};
+use axum::{body::Bytes, extract::{Path, Query, State}, response::IntoResponse, routing::{delete, get, post, put}, Json, Router};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use uuid::Uuid;
 
-use crate::{render::{effects::{AnyEffect, AnyTemporaryEffect, SolidColorEffect}, frame::PixelColor}, LightingState, TOTAL_PIXELS};
+use crate::{interface::alarms::Alarm, render::{effects::{AnyEffect, AnyTemporaryEffect, NodeGroup, RippleTemporaryEffect, SolidColorEffect}, expressions::AnyColorExpression, frame::PixelColor, image::Image, spatial_map::Location, white_calibration::WhiteCalibration, RenderState}, LightingState, TOTAL_PIXELS};
 
 // TODO: Authentication
 
@@ -20,7 +20,27 @@ pub fn router() -> Router<Arc<LightingState>> {
         .route("/effect_preset/:effect_id", get(get_effect_preset_handler))
         .route("/effect_preset/:effect_id", put(update_effect_preset_handler))
         .route("/effect_preset/:effect_id", delete(delete_effect_preset_handler))
+        .route("/node_groups", get(get_node_groups_handler))
+        .route("/node_group", post(create_node_group_handler))
+        .route("/node_group/:group_id", get(get_node_group_handler))
+        .route("/node_group/:group_id", put(update_node_group_handler))
+        .route("/node_group/:group_id", delete(delete_node_group_handler))
+        .route("/variables", get(get_variables_handler))
+        .route("/variable/:name", put(set_variable_handler))
+        .route("/variable/:name", delete(delete_variable_handler))
+        .route("/run_temporary_effect", post(run_arbitrary_temporary_effect_handler))
         .route("/run_temporary_effect/:effect_id", post(run_temporary_effect_handler))
+        .route("/ripple", post(ripple_handler))
+        .route("/images", get(get_images_handler))
+        .route("/image/:name", put(upload_image_handler))
+        .route("/alarms", get(get_alarms_handler))
+        .route("/alarm", post(create_alarm_handler))
+        .route("/alarm/:alarm_id", put(update_alarm_handler))
+        .route("/alarm/:alarm_id", delete(delete_alarm_handler))
+        .route("/alarm/snooze", post(snooze_alarm_handler))
+        .route("/alarm/dismiss", post(dismiss_alarm_handler))
+        .route("/white_calibration", get(get_white_calibration_handler))
+        .route("/white_calibration", put(set_white_calibration_handler))
         .route("/run_effect", post(run_arbitrary_effect_handler))
         .route("/run_effect/:effect_id", post(run_effect_handler));
 
@@ -33,10 +53,10 @@ async fn run_arbitrary_effect_handler(
 ) -> impl IntoResponse {
     match effect {
         Some(e) => {
-            state.render_state.lock().effect = Box::new(e);
+            RenderState::set_effect(&state.render_state, e);
         }
         None => {
-            state.render_state.lock().effect = Box::new(SolidColorEffect::new(
+            RenderState::set_effect(&state.render_state, SolidColorEffect::new(
                 PixelColor::BLACK, 0, TOTAL_PIXELS
             ));
         }
@@ -58,7 +78,7 @@ async fn run_effect_handler(
     let effect = effect_presets.get_preset(id);
     
     if let Some(effect) = effect {
-        state.render_state.lock().effect = Box::new(effect);
+        RenderState::set_effect(&state.render_state, effect);
     }
 
     json!({ "status": "OK" }).to_string()
@@ -76,12 +96,193 @@ async fn run_temporary_effect_handler(
     let effect = effect_presets.get_temporary_effect(id);
     
     if let Some(effect) = effect {
-        state.render_state.lock().temporary_effect_compositor.add_effect(effect);
+        RenderState::add_temporary_effect(&state.render_state, effect);
     }
 
     json!({ "status": "OK" }).to_string()
 }
 
+async fn run_arbitrary_temporary_effect_handler(
+    State(state): State<Arc<LightingState>>,
+    Json(effect): Json<AnyTemporaryEffect>
+) -> impl IntoResponse {
+    RenderState::add_temporary_effect(&state.render_state, effect);
+    json!({ "status": "OK" }).to_string()
+}
+
+/// Options for a ripple. The ripple starts from a named origin, a location in meters, or the location of a pixel,
+/// and everything else is optional.
+#[derive(Serialize, Deserialize)]
+struct RippleParams {
+    /// The name of a location in the spatial map, like "center".
+    origin: Option<String>,
+    x: Option<f32>,
+    y: Option<f32>,
+    pixel: Option<u32>,
+    /// Defaults to white.
+    color: Option<AnyColorExpression>,
+    /// How fast the rings expand, in meters per second. Defaults to 2.
+    speed: Option<f64>,
+    /// The width of each ring, in meters. Defaults to 0.5.
+    width: Option<f64>,
+    /// The number of rings. Defaults to 3.
+    rings: Option<u32>,
+    /// The time between rings, in seconds. Defaults to 0.4.
+    interval: Option<f64>
+}
+
+/// Starts a ripple with the options sent as a JSON body.
+async fn ripple_handler(
+    State(state): State<Arc<LightingState>>,
+    Json(params): Json<RippleParams>
+) -> impl IntoResponse {
+    let mut render_state = state.render_state.lock();
+    let origin = match &params {
+        RippleParams { origin: Some(name), .. } => match render_state.info.named_locations.iter().find(|(location_name, _)| location_name == name) {
+            Some((_, location)) => location.clone(),
+            None => return json!({ "status": "Error", "message": format!("Unknown location {}", name) }).to_string()
+        },
+        RippleParams { x: Some(x), y: Some(y), .. } => Location::new(*x, *y),
+        RippleParams { pixel: Some(pixel), .. } => match render_state.info.pixel_locations.get(*pixel as usize) {
+            Some(location) => location.clone(),
+            None => return json!({ "status": "Error", "message": "Pixel out of range" }).to_string()
+        },
+        _ => return json!({ "status": "Error", "message": "Expected an origin, a pixel, or an x and y location" }).to_string()
+    };
+
+    render_state.temporary_effect_compositor.add_effect(RippleTemporaryEffect::new(
+        origin,
+        params.color.unwrap_or_else(|| PixelColor::new(255, 255, 255, 1.0).into()),
+        params.speed.unwrap_or(2.),
+        params.width.unwrap_or(0.5),
+        params.rings.unwrap_or(3),
+        params.interval.unwrap_or(0.4)
+    ));
+    json!({ "status": "OK" }).to_string()
+}
+
+async fn get_images_handler() -> impl IntoResponse {
+    Json(Image::list())
+}
+
+/// Saves a GIF to the controller so image effects can play it.
+async fn upload_image_handler(
+    Path(name): Path<String>,
+    data: Bytes
+) -> impl IntoResponse {
+    match Image::save(&name, &data) {
+        Ok(_) => json!({ "status": "OK" }).to_string(),
+        Err(e) => json!({ "status": "Error", "message": e }).to_string()
+    }
+}
+
+async fn get_alarms_handler(
+    State(state): State<Arc<LightingState>>
+) -> impl IntoResponse {
+    let alarms = state.alarms.read().await;
+    Json(alarms.get_alarms().to_vec())
+}
+
+async fn create_alarm_handler(
+    State(state): State<Arc<LightingState>>,
+    Json(alarm): Json<Alarm>
+) -> impl IntoResponse {
+    let mut alarms = state.alarms.write().await;
+    match alarms.add_alarm(alarm) {
+        Ok(_) => json!({ "status": "OK" }).to_string(),
+        Err(e) => json!({ "status": "Error", "message": e.to_string() }).to_string()
+    }
+}
+
+async fn update_alarm_handler(
+    State(state): State<Arc<LightingState>>,
+    Path(alarm_id): Path<String>,
+    Json(alarm): Json<Alarm>
+) -> impl IntoResponse {
+    let mut alarms = state.alarms.write().await;
+    let id = match Uuid::parse_str(&alarm_id) {
+        Ok(id) => id,
+        Err(_) => return json!({ "status": "Error", "message": "Invalid UUID" }).to_string(),
+    };
+    match alarms.update_alarm(id, alarm) {
+        Ok(_) => json!({ "status": "OK" }).to_string(),
+        Err(e) => json!({ "status": "Error", "message": e.to_string() }).to_string()
+    }
+}
+
+async fn delete_alarm_handler(
+    State(state): State<Arc<LightingState>>,
+    Path(alarm_id): Path<String>
+) -> impl IntoResponse {
+    let mut alarms = state.alarms.write().await;
+    let id = match Uuid::parse_str(&alarm_id) {
+        Ok(id) => id,
+        Err(_) => return json!({ "status": "Error", "message": "Invalid UUID" }).to_string(),
+    };
+    match alarms.remove_alarm(id) {
+        Ok(_) => json!({ "status": "OK" }).to_string(),
+        Err(e) => json!({ "status": "Error", "message": e.to_string() }).to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnoozeParams {
+    /// How long to snooze for, in minutes. Defaults to 9.
+    minutes: Option<f64>
+}
+
+/// Turns off the running alarm sunrises for a while.
+async fn snooze_alarm_handler(
+    State(state): State<Arc<LightingState>>,
+    Query(params): Query<SnoozeParams>
+) -> impl IntoResponse {
+    let mut render_state = state.render_state.lock();
+    let until = render_state.info.time + params.minutes.unwrap_or(9.) * 60.;
+    let render_state = &mut *render_state;
+    let effects = render_state.alarm_effect_compositor.effects_mut()
+        .chain(render_state.temporary_effect_compositor.effects_mut());
+    for effect in effects {
+        if let AnyTemporaryEffect::Sunrise(sunrise) = effect {
+            sunrise.snooze(until);
+        }
+    }
+    json!({ "status": "OK" }).to_string()
+}
+
+/// Stops the running alarm sunrises.
+async fn dismiss_alarm_handler(
+    State(state): State<Arc<LightingState>>
+) -> impl IntoResponse {
+    let mut render_state = state.render_state.lock();
+    let render_state = &mut *render_state;
+    let effects = render_state.alarm_effect_compositor.effects_mut()
+        .chain(render_state.temporary_effect_compositor.effects_mut());
+    for effect in effects {
+        if let AnyTemporaryEffect::Sunrise(sunrise) = effect {
+            sunrise.dismiss();
+        }
+    }
+    json!({ "status": "OK" }).to_string()
+}
+
+async fn get_white_calibration_handler(
+    State(state): State<Arc<LightingState>>
+) -> impl IntoResponse {
+    Json(state.render_state.lock().info.white_calibration.clone())
+}
+
+/// Replaces the white calibration used by white effects and saves it.
+async fn set_white_calibration_handler(
+    State(state): State<Arc<LightingState>>,
+    Json(calibration): Json<WhiteCalibration>
+) -> impl IntoResponse {
+    if let Err(e) = calibration.save() {
+        return json!({ "status": "Error", "message": e.to_string() }).to_string();
+    }
+    state.render_state.lock().info.white_calibration = calibration;
+    json!({ "status": "OK" }).to_string()
+}
+
 async fn get_temporary_effect_handlers(
     State(state): State<Arc<LightingState>>
 ) -> impl IntoResponse {
@@ -215,4 +416,110 @@ async fn delete_effect_preset_handler(
     };
     effect_presets.remove_preset(id).unwrap();
     json!({ "status": "OK" }).to_string()
+}
+
+async fn get_node_groups_handler(
+    State(state): State<Arc<LightingState>>
+) -> impl IntoResponse {
+    let effect_presets = state.presets.read().await;
+    let groups = effect_presets.get_node_group_list();
+    Json(shared::NodeGroupList { groups })
+}
+
+async fn get_node_group_handler(
+    State(state): State<Arc<LightingState>>,
+    Path(group_id): Path<String>
+) -> impl IntoResponse {
+    let effect_presets = state.presets.read().await;
+    let id = match Uuid::parse_str(&group_id) {
+        Ok(id) => id,
+        Err(_) => return Err("Invalid UUID"),
+    };
+    let group = effect_presets.get_node_group(id);
+    if let Some(group) = group {
+        Ok(Json(group))
+    } else {
+        Err("Not found")
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CreateNodeGroupParams {
+    name: String
+}
+
+async fn create_node_group_handler(
+    State(state): State<Arc<LightingState>>,
+    Query(params): Query<CreateNodeGroupParams>,
+    Json(group): Json<NodeGroup>
+) -> impl IntoResponse {
+    let mut effect_presets = state.presets.write().await;
+    match effect_presets.add_node_group(params.name, group) {
+        Ok(_) => json!({ "status": "OK" }).to_string(),
+        Err(e) => json!({ "status": "Error", "message": e.to_string() }).to_string(),
+    }
+}
+
+async fn update_node_group_handler(
+    State(state): State<Arc<LightingState>>,
+    Path(group_id): Path<String>,
+    Query(params): Query<CreateNodeGroupParams>,
+    Json(group): Json<NodeGroup>
+) -> impl IntoResponse {
+    let mut effect_presets = state.presets.write().await;
+    let id = match Uuid::parse_str(&group_id) {
+        Ok(id) => id,
+        Err(_) => return json!({ "status": "Error", "message": "Invalid UUID" }).to_string(),
+    };
+
+    match effect_presets.update_node_group(id, params.name, group) {
+        Ok(_) => json!({ "status": "OK" }).to_string(),
+        Err(e) => json!({ "status": "Error", "message": e.to_string() }).to_string(),
+    }
+}
+
+async fn delete_node_group_handler(
+    State(state): State<Arc<LightingState>>,
+    Path(group_id): Path<String>
+) -> impl IntoResponse {
+    let mut effect_presets = state.presets.write().await;
+    let id = match Uuid::parse_str(&group_id) {
+        Ok(id) => id,
+        Err(_) => return json!({ "status": "Error", "message": "Invalid UUID" }).to_string(),
+    };
+    match effect_presets.remove_node_group(id) {
+        Ok(_) => json!({ "status": "OK" }).to_string(),
+        Err(e) => json!({ "status": "Error", "message": e.to_string() }).to_string(),
+    }
+}
+
+async fn get_variables_handler(
+    State(state): State<Arc<LightingState>>
+) -> impl IntoResponse {
+    let mut variables = state.render_state.lock().info.variables.list()
+        .into_iter()
+        .map(|(name, value)| shared::Variable { name, value })
+        .collect::<Vec<_>>();
+    variables.sort_by(|a, b| a.name.cmp(&b.name));
+    Json(shared::VariableList { variables })
+}
+
+async fn set_variable_handler(
+    State(state): State<Arc<LightingState>>,
+    Path(name): Path<String>,
+    Json(value): Json<f64>
+) -> impl IntoResponse {
+    state.render_state.lock().info.variables.set(name, value);
+    json!({ "status": "OK" }).to_string()
+}
+
+async fn delete_variable_handler(
+    State(state): State<Arc<LightingState>>,
+    Path(name): Path<String>
+) -> impl IntoResponse {
+    if state.render_state.lock().info.variables.remove(&name) {
+        json!({ "status": "OK" }).to_string()
+    } else {
+        json!({ "status": "Error", "message": "Variable not found" }).to_string()
+    }
 }
\ No newline at end of file
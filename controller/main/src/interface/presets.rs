@@ -3,7 +3,7 @@ use std::{io::{Error, ErrorKind}, path::PathBuf};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{render::{effects::{self, AnyEffect, AnyTemporaryEffect}, expressions, frame::PixelColor}, TOTAL_PIXELS};
+use crate::{render::{effects::{self, AnyEffect, AnyTemporaryEffect, NodeGroup}, expressions, frame::PixelColor, gradient::{Gradient, GradientInterpolation}}, TOTAL_PIXELS};
 
 static EFFECT_PRESET_FILE: &str = "effect_presets.json";
 
@@ -43,11 +43,31 @@ impl TemporaryEffectPreset {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+struct NodeGroupPreset {
+    name: String,
+    group: NodeGroup,
+    id: Uuid
+}
+
+impl NodeGroupPreset {
+    fn new(name: String, group: NodeGroup) -> Self {
+        NodeGroupPreset {
+            name,
+            group,
+            id: Uuid::new_v4()
+        }
+    }
+}
+
 /// Stores the web interface effect presets and persists them to disk.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct EffectPresets {
     presets: Vec<EffectPreset>,
-    temporary_effects: Vec<TemporaryEffectPreset>
+    temporary_effects: Vec<TemporaryEffectPreset>,
+    /// The library of node groups that can be added to node editor effects.
+    #[serde(default)]
+    node_groups: Vec<NodeGroupPreset>
 }
 
 impl EffectPresets {
@@ -90,15 +110,15 @@ impl EffectPresets {
                         expressions::MultiplyExpression::new(
                             expressions::CurrentTimeExpression::new().into(),
                             expressions::LiteralExpression::new(84.0).into()
-                        ).into()
+                        )
                     )
                 ),
                 EffectPreset::new(
                     "Music visualizer".to_string(),
                     "fas fa-music".to_string(),
                     effects::RotateEffect::new(
-                        effects::MusicVisualizerEffect::new(shared::constants::MUSIC_VISUALIZER_PORT).into(),
-                        expressions::LiteralExpression::new(-219.0).into()
+                        effects::MusicVisualizerEffect::new(None).into(),
+                        -219.0
                     )
                 ),
                 EffectPreset::new(
@@ -106,6 +126,52 @@ impl EffectPresets {
                     "fas fa-bolt".to_string(),
                     effects::FlashingColorEffect::new(1., 0., PixelColor::new(255, 0, 0, 1.0), PixelColor::new(255, 0, 0, 0.0)).into()
                 ),
+                EffectPreset::new(
+                    "Fire".to_string(),
+                    "fas fa-fire".to_string(),
+                    effects::FireEffect::new(0.3, 0.5, Gradient::evenly_spaced(vec![
+                        PixelColor::new(0, 0, 0, 1.0),
+                        PixelColor::new(180, 0, 0, 1.0),
+                        PixelColor::new(255, 100, 0, 1.0),
+                        PixelColor::new(255, 220, 60, 1.0),
+                        PixelColor::new(255, 255, 200, 1.0),
+                    ], GradientInterpolation::RGB, false), effects::FireBase::Both)
+                ),
+                EffectPreset::new(
+                    "Twinkle".to_string(),
+                    "fas fa-star".to_string(),
+                    effects::TwinkleEffect::new(0.08, 0.6, 1.2, Gradient::evenly_spaced(vec![
+                        PixelColor::new(255, 255, 255, 1.0),
+                        PixelColor::new(255, 200, 120, 1.0),
+                        PixelColor::new(150, 180, 255, 1.0),
+                    ], GradientInterpolation::OKLab, false), 0)
+                ),
+                EffectPreset::new(
+                    "Comets".to_string(),
+                    "fas fa-meteor".to_string(),
+                    effects::CometEffect::new(3, 1.5, 1.2, Gradient::evenly_spaced(vec![
+                        PixelColor::new(255, 255, 255, 1.0),
+                        PixelColor::new(0, 150, 255, 1.0),
+                        PixelColor::new(80, 0, 255, 1.0),
+                    ], GradientInterpolation::OKLab, false), effects::CometMode::Wrap, effects::CometPath::Perimeter)
+                ),
+                EffectPreset::new(
+                    "Clock".to_string(),
+                    "fas fa-clock".to_string(),
+                    effects::ClockEffect::new(
+                        Some(effects::ClockMarker::new(PixelColor::new(255, 60, 0, 1.0), 14.)),
+                        Some(effects::ClockMarker::new(PixelColor::new(0, 200, 255, 1.0), 8.)),
+                        Some(effects::ClockMarker::new(PixelColor::new(255, 255, 255, 1.0), 4.)),
+                        Some(effects::ClockMarker::new(PixelColor::new(255, 255, 255, 0.3), 2.)),
+                        90.,
+                        true
+                    )
+                ),
+                EffectPreset::new(
+                    "Warm white".to_string(),
+                    "fas fa-lightbulb".to_string(),
+                    effects::WhiteEffect::new(2700., 1.)
+                ),
                 EffectPreset::new(
                     "Solid white".to_string(),
                     "fas fa-sun".to_string(),
@@ -117,7 +183,8 @@ impl EffectPresets {
                     effects::SolidColorEffect::new(PixelColor::new(0, 0, 0, 1.0), 0, TOTAL_PIXELS)
                 )
             ],
-            temporary_effects: vec![]
+            temporary_effects: vec![],
+            node_groups: vec![]
         }
     }
 
@@ -139,6 +206,13 @@ impl EffectPresets {
         Ok(())
     }
 
+    pub fn add_node_group(&mut self, name: String, group: NodeGroup) -> Result<(), Error> {
+        let preset = NodeGroupPreset::new(name, group);
+        self.node_groups.push(preset);
+        self.save()?;
+        Ok(())
+    }
+
     pub fn update_preset(&mut self, id: Uuid, name: String, icon: String, effect: AnyEffect) -> Result<(), Error> {
         let index = self.presets.iter().position(|preset| preset.id == id);
         if let Some(index) = index {
@@ -170,6 +244,21 @@ impl EffectPresets {
         }
     }
 
+    pub fn update_node_group(&mut self, id: Uuid, name: String, group: NodeGroup) -> Result<(), Error> {
+        let index = self.node_groups.iter().position(|preset| preset.id == id);
+        if let Some(index) = index {
+            self.node_groups[index] = NodeGroupPreset {
+                name,
+                group,
+                id
+            };
+            self.save()?;
+            Ok(())
+        } else {
+            Err(Error::new(ErrorKind::NotFound, "Node group not found"))
+        }
+    }
+
     pub fn remove_preset(&mut self, id: Uuid) -> Result<(), Error> {
         let index = self.presets.iter().position(|preset| preset.id == id);
         if let Some(index) = index {
@@ -192,6 +281,17 @@ impl EffectPresets {
         }
     }
 
+    pub fn remove_node_group(&mut self, id: Uuid) -> Result<(), Error> {
+        let index = self.node_groups.iter().position(|preset| preset.id == id);
+        if let Some(index) = index {
+            self.node_groups.remove(index);
+            self.save()?;
+            Ok(())
+        } else {
+            Err(Error::new(ErrorKind::NotFound, "Node group not found"))
+        }
+    }
+
     pub fn get_preset(&self, uuid: Uuid) -> Option<AnyEffect> {
         self.presets.iter().find(|preset| preset.id == uuid).map(|preset| preset.effect.clone())
     }
@@ -200,6 +300,10 @@ impl EffectPresets {
         self.temporary_effects.iter().find(|preset| preset.id == uuid).map(|preset| preset.effect.clone())
     }
 
+    pub fn get_node_group(&self, uuid: Uuid) -> Option<NodeGroup> {
+        self.node_groups.iter().find(|preset| preset.id == uuid).map(|preset| preset.group.clone())
+    }
+
     pub fn get_temporary_effect_list(&self) -> Vec<shared::TemporaryEffect> {
         self.temporary_effects.iter().map(|preset| shared::TemporaryEffect {
             id: preset.id.to_string(),
@@ -215,6 +319,13 @@ impl EffectPresets {
         }).collect()
     }
 
+    pub fn get_node_group_list(&self) -> Vec<shared::NodeGroup> {
+        self.node_groups.iter().map(|preset| shared::NodeGroup {
+            id: preset.id.to_string(),
+            name: preset.name.clone()
+        }).collect()
+    }
+
     fn save(&self) -> Result<(), Error> {
         let file = std::fs::File::create(EffectPresets::get_file_path())?;
         serde_json::to_writer(file, self)?;
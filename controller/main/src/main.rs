@@ -3,14 +3,15 @@
 
 use std::sync::Arc;
 
-use interface::presets::EffectPresets;
+use interface::{alarms::Alarms, presets::EffectPresets};
 use parking_lot::Mutex;
-use render::{effects::{self, TemporaryEffectCompositor}, frame::PixelColor, spatial_map::{Location, SpatialMap}, RenderInfo, RenderState};
+use render::{effects::{self, TemporaryEffectCompositor}, frame::PixelColor, spatial_map::{Location, SpatialMap}, white_calibration::WhiteCalibration, RenderInfo, RenderState};
 use tokio::sync::RwLock;
 
 mod output;
 mod interface;
 mod render;
+mod benchmark;
 
 static FRAME_TIMES_STORED: usize = 100;
 
@@ -19,33 +20,49 @@ static TOTAL_PIXELS: u32 = 812;
 // Shared global state for the web application
 struct LightingState {
     render_state: Arc<Mutex<RenderState>>,
-    presets: RwLock<EffectPresets>
+    presets: RwLock<EffectPresets>,
+    alarms: RwLock<Alarms>
 }
 
-#[tokio::main]
-async fn main() {
+fn spatial_map() -> SpatialMap {
     // TODO: Load this from a configuration file
-    let pixel_locations = SpatialMap::new(TOTAL_PIXELS)
+    let mut spatial_map = SpatialMap::new(TOTAL_PIXELS);
+    spatial_map
         .add_span(-14, 187, Location::from_inches(0., 0.), Location::from_inches(0., 132.))
         .add_span(187, 406, Location::from_inches(0., 132.), Location::from_inches(144., 132.))
         .add_span(406, 558, Location::from_inches(144., 132.), Location::from_inches(144., 32.))
         .add_span(558, 623, Location::from_inches(144., 32.), Location::from_inches(114., 0.))
         .add_span(623, 798, Location::from_inches(114., 0.), Location::from_inches(0., 0.))
-        .get_individual_pixel_locations()
-        .try_into().unwrap();
+        .add_named_location("center", Location::from_inches(72., 66.));
+    spatial_map
+}
+
+#[tokio::main]
+async fn main() {
+    if std::env::args().any(|arg| arg == "--benchmark") {
+        let passed = benchmark::run(std::env::args().any(|arg| arg == "--save-baseline"));
+        std::process::exit(if passed { 0 } else { 1 });
+    }
 
     let lighting_state = Arc::new(LightingState {
         render_state: Arc::new(Mutex::new(RenderState {
-            info: RenderInfo::new(pixel_locations),
+            info: RenderInfo {
+                white_calibration: WhiteCalibration::load(),
+                ..RenderInfo::new(&spatial_map())
+            },
             temporary_effect_compositor: TemporaryEffectCompositor::new(vec![]),
+            alarm_effect_compositor: TemporaryEffectCompositor::new(vec![]),
             effect: effects::SolidColorEffect::new(PixelColor::new(0, 0, 0, 1.0), 0, TOTAL_PIXELS).into()
         })),
-        presets: RwLock::new(EffectPresets::load())
+        presets: RwLock::new(EffectPresets::load()),
+        alarms: RwLock::new(Alarms::load())
     });
 
     let (render_thread, render_consumer) =
         render::start_render_thread(Arc::clone(&lighting_state.render_state));
     output::start_output_thread(render_thread.thread().clone(), render_consumer);
 
+    tokio::spawn(interface::alarms::run_scheduler(Arc::clone(&lighting_state)));
+
     interface::serve(lighting_state).await;
 }
\ No newline at end of file
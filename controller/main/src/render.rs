@@ -1,12 +1,15 @@
 use std::{sync::Arc, thread::JoinHandle, time::Duration};
 
-use effects::{AnyEffect, RenderContext, TemporaryEffectCompositor};
+use effects::{AnyEffect, AnyTemporaryEffect, RenderContext, TemporaryEffectCompositor};
 use filters::Filter;
 use frame::PresentedFrame;
 use parking_lot::Mutex;
 use ringbuf::{traits::{Observer, Producer, Split}, StaticRb};
-use spatial_map::Location;
+use spatial_map::{Location, SpatialMap};
 use thread_priority::{ThreadBuilderExt, ThreadPriority, ThreadPriorityValue};
+use variables::Variables;
+use audio::{AudioData, AudioReceiver};
+use white_calibration::WhiteCalibration;
 
 use crate::{FRAME_TIMES_STORED, TOTAL_PIXELS};
 
@@ -15,6 +18,13 @@ pub mod expressions;
 mod filters;
 pub mod spatial_map;
 pub mod frame;
+pub mod gradient;
+pub mod image;
+pub mod local_time;
+pub mod random;
+pub mod variables;
+pub mod audio;
+pub mod white_calibration;
 mod idle_tracker;
 
 // State for rendering the lights that needs to be shared between the web server and the output thread
@@ -22,12 +32,28 @@ mod idle_tracker;
 pub struct RenderState {
     pub info: RenderInfo,
     pub temporary_effect_compositor: TemporaryEffectCompositor,
+    /// Alarm sunrises run separately from the other temporary effects so they start on time,
+    /// and are drawn under them so ripples and other notifications still show.
+    pub alarm_effect_compositor: TemporaryEffectCompositor,
     pub effect: Box<AnyEffect>
 }
 
 impl RenderState {
-    fn split(&mut self) -> (&mut RenderInfo, &mut TemporaryEffectCompositor, &mut dyn effects::Effect) {
-        (&mut self.info, &mut self.temporary_effect_compositor, self.effect.as_mut())
+    /// Replaces the effect being rendered, optimizing it first. The effect is optimized before the
+    /// render state is locked, since optimizing can take a while, like when images are decoded.
+    pub fn set_effect(render_state: &Mutex<RenderState>, mut effect: AnyEffect) {
+        effects::Effect::optimize(&mut effect);
+        *render_state.lock().effect = effect;
+    }
+
+    /// Adds a temporary effect after the others, optimizing it before the render state is locked like `set_effect`.
+    pub fn add_temporary_effect(render_state: &Mutex<RenderState>, mut effect: AnyTemporaryEffect) {
+        effects::Effect::optimize(&mut effect);
+        render_state.lock().temporary_effect_compositor.add_effect(effect);
+    }
+
+    fn split(&mut self) -> (&mut RenderInfo, &mut TemporaryEffectCompositor, &mut TemporaryEffectCompositor, &mut dyn effects::Effect) {
+        (&mut self.info, &mut self.alarm_effect_compositor, &mut self.temporary_effect_compositor, self.effect.as_mut())
     }
 }
 
@@ -46,11 +72,21 @@ pub struct RenderInfo {
     pub debug_text: String,
     pub idle: bool,
     pub pixel_locations: [Location; TOTAL_PIXELS as usize],
-    pub websocket_input: Option<Vec<u8>>
+    /// The pixels of every span in the spatial map, in order along the span.
+    pub pixel_segments: Vec<Vec<u32>>,
+    /// Named points in the room from the spatial map.
+    pub named_locations: Vec<(String, Location)>,
+    pub websocket_input: Option<Vec<u8>>,
+    /// Named values that can be changed at runtime to adjust running effects.
+    pub variables: Variables,
+    /// The latest audio data from the music visualizer client.
+    pub audio: AudioData,
+    /// The white point correction for this installation's LEDs.
+    pub white_calibration: WhiteCalibration
 }
 
 impl RenderInfo {
-    pub fn new(pixel_locations: [Location; TOTAL_PIXELS as usize]) -> Self {
+    pub fn new(spatial_map: &SpatialMap) -> Self {
         Self {
             time: 0.0,
             frame_times: [0.0; FRAME_TIMES_STORED],
@@ -58,8 +94,13 @@ impl RenderInfo {
             current_presented_frame: None,
             debug_text: "".to_string(),
             idle: false,
-            pixel_locations,
-            websocket_input: None
+            pixel_locations: spatial_map.get_individual_pixel_locations().try_into().unwrap(),
+            pixel_segments: spatial_map.get_segments(),
+            named_locations: spatial_map.get_named_locations(),
+            websocket_input: None,
+            variables: Variables::default(),
+            audio: AudioData::default(),
+            white_calibration: WhiteCalibration::default()
         }
     }
 }
@@ -72,11 +113,16 @@ type RenderRingBuf = StaticRb::<PresentedFrame, RENDER_BUFFER_SIZE>;
 pub type RenderRingBufConsumer = <RenderRingBuf as Split>::Cons;
 type RenderRingBufProducer = <RenderRingBuf as Split>::Prod;
 
-pub fn render_frame(delta: Duration, render_state: &Arc<Mutex<RenderState>>, filters: &Vec<Box<dyn Filter>>) -> Option<PresentedFrame> {
+pub fn render_frame(
+    delta: Duration,
+    render_state: &Arc<Mutex<RenderState>>,
+    filters: &Vec<Box<dyn Filter>>,
+    audio_receiver: Option<&mut AudioReceiver>
+) -> Option<PresentedFrame> {
     // We should never hold a lock on the render state for a significant amount of time in other threads
     match render_state.try_lock_for(Duration::from_millis(1)) {
         Some(mut state) => {
-            let (info, temporary_effect_compositor, effect) = state.split();
+            let (info, alarm_effect_compositor, temporary_effect_compositor, effect) = state.split();
 
             info.time += delta.as_secs_f64();
             
@@ -85,6 +131,12 @@ pub fn render_frame(delta: Duration, render_state: &Arc<Mutex<RenderState>>, fil
             let frames = info.frames;
             info.frame_times[frames % FRAME_TIMES_STORED] = delta.as_secs_f64();
 
+            info.variables.update(delta);
+            info.audio.advance(delta);
+            if let Some(audio_receiver) = audio_receiver {
+                audio_receiver.receive(&mut info.audio);
+            }
+
             // Render the effect
             let context = RenderContext {
                 delta,
@@ -93,6 +145,7 @@ pub fn render_frame(delta: Duration, render_state: &Arc<Mutex<RenderState>>, fil
             };
             let effect_frame = effects::AlphaCompositorEffect::composite(vec![
                 effect,
+                alarm_effect_compositor,
                 temporary_effect_compositor
             ], context, info);
 
@@ -124,6 +177,14 @@ fn run_render_thread(render_state: Arc<Mutex<RenderState>>, mut producer: Render
         filters::GammaCorrectionFilter::new(2.2)
     ];
 
+    let mut audio_receiver = match AudioReceiver::bind(shared::constants::MUSIC_VISUALIZER_PORT) {
+        Ok(receiver) => Some(receiver),
+        Err(e) => {
+            eprintln!("Failed to listen for music visualizer audio data: {}", e);
+            None
+        }
+    };
+
     let mut idle_tracker = idle_tracker::IdleTracker::new(
         Duration::from_secs(30),
         Duration::from_secs(0),
@@ -144,7 +205,7 @@ fn run_render_thread(render_state: Arc<Mutex<RenderState>>, mut producer: Render
             let delta = start_time - last_frame_time;
             last_frame_time = start_time;
     
-            if let Some(frame) = render_frame(delta, &render_state, &filters) {
+            if let Some(frame) = render_frame(delta, &render_state, &filters, audio_receiver.as_mut()) {
                 idle_tracker.update(&frame);
                 render_state.try_lock_for(Duration::from_millis(1)).map(|mut state| {
                     state.info.idle = idle_tracker.is_idle();
@@ -0,0 +1,132 @@
+use std::{io::Error, net::{Ipv4Addr, SocketAddr, UdpSocket}, time::{Duration, Instant}};
+
+/// How quickly audio values fade out when no audio data is published, as a fraction left after a second.
+static FADE_PER_SECOND: f64 = 0.5;
+
+/// How long the beat pulse takes to decay most of the way to 0, in seconds.
+static BEAT_DECAY_TIME: f64 = 0.15;
+/// How much louder than its recent average the bass has to be to count as a beat.
+static BEAT_THRESHOLD: f64 = 1.4;
+/// The quietest bass that can count as a beat, so noise during silence doesn't.
+static BEAT_MIN_LEVEL: f64 = 0.1;
+/// The shortest time between beats, in seconds.
+static BEAT_MIN_INTERVAL: f64 = 0.25;
+/// How long the average bass level used to detect beats is taken over, in seconds.
+static BEAT_AVERAGE_TIME: f64 = 1.;
+
+/// The bars are spread logarithmically from about 50Hz to 10kHz, so bass (below 250Hz) is roughly
+/// the lowest 30% and treble (above 2kHz) is roughly the highest 30%.
+static BASS_END: f64 = 0.3;
+static TREBLE_START: f64 = 0.7;
+
+/// The latest audio data from the music visualizer client, so any effect can react to music.
+/// All values are from 0 to 1.
+#[derive(Debug, Default)]
+pub struct AudioData {
+    /// The spectrum bars as received from the music visualizer client.
+    pub spectrum: Vec<f32>,
+    /// The average level over the whole spectrum.
+    pub level: f64,
+    pub bass: f64,
+    pub mid: f64,
+    pub treble: f64,
+    /// Jumps to 1 on every detected beat and quickly decays back to 0.
+    pub beat: f64,
+    /// When audio data was last received, if it ever was.
+    pub last_received: Option<Instant>,
+    /// Whether audio data was received this frame.
+    pub received: bool,
+
+    average_bass: f64,
+    /// The time left until another beat can be detected, in seconds.
+    beat_cooldown: f64,
+    delta: f64
+}
+
+impl AudioData {
+    /// Fades the audio values and the beat pulse. Called at the start of every frame,
+    /// so the values fall to 0 if nothing publishes audio data.
+    pub fn advance(&mut self, delta: Duration) {
+        let delta = delta.as_secs_f64();
+        let fade = FADE_PER_SECOND.powf(delta);
+        self.level *= fade;
+        self.bass *= fade;
+        self.mid *= fade;
+        self.treble *= fade;
+        self.beat *= (-delta / BEAT_DECAY_TIME).exp();
+        for value in &mut self.spectrum {
+            *value *= fade as f32;
+        }
+        self.received = false;
+
+        self.beat_cooldown -= delta;
+        self.delta = delta;
+    }
+
+    /// Publishes new spectrum data, with bar values from 0 to 255.
+    /// The data is in the music visualizer's stereo layout: the lowest frequencies are in the
+    /// middle, and frequencies increase toward both ends.
+    pub fn publish(&mut self, spectrum: &[u8]) {
+        self.spectrum.clear();
+        self.spectrum.extend(spectrum.iter().map(|&value| value as f32));
+        self.last_received = Some(Instant::now());
+        self.received = true;
+        if spectrum.is_empty() {
+            return;
+        }
+
+        let mut bands = [(0., 0); 3];
+        for (i, &value) in spectrum.iter().enumerate() {
+            // 0 for the lowest frequency, 1 for the highest
+            let frequency = ((i as f64 + 0.5) / spectrum.len() as f64 * 2. - 1.).abs();
+            let band = if frequency < BASS_END { 0 } else if frequency < TREBLE_START { 1 } else { 2 };
+            bands[band].0 += value as f64 / 255.;
+            bands[band].1 += 1;
+        }
+        let average = |(total, count): (f64, usize)| if count == 0 { 0. } else { total / count as f64 };
+
+        self.level = spectrum.iter().map(|&value| value as f64 / 255.).sum::<f64>() / spectrum.len() as f64;
+        self.bass = average(bands[0]);
+        self.mid = average(bands[1]);
+        self.treble = average(bands[2]);
+
+        if self.bass > self.average_bass * BEAT_THRESHOLD && self.bass > BEAT_MIN_LEVEL && self.beat_cooldown <= 0. {
+            self.beat = 1.;
+            self.beat_cooldown = BEAT_MIN_INTERVAL;
+        }
+        self.average_bass += (self.bass - self.average_bass) * (1. - (-self.delta / BEAT_AVERAGE_TIME).exp());
+    }
+}
+
+/// Receives audio data from the music visualizer client over UDP and publishes it every frame,
+/// whatever effect is running.
+#[derive(Debug)]
+pub struct AudioReceiver {
+    socket: UdpSocket,
+    buffer: Vec<u8>
+}
+
+impl AudioReceiver {
+    pub fn bind(port: u16) -> Result<Self, Error> {
+        let socket = UdpSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)))?;
+        socket.set_nonblocking(true)?;
+        println!("Listening for music visualizer audio data on port {}", port);
+
+        Ok(AudioReceiver {
+            socket,
+            // Larger than any packet the client sends, so they aren't truncated
+            buffer: vec![0; 4096]
+        })
+    }
+
+    /// Publishes the newest audio data that arrived since the last frame, if any.
+    pub fn receive(&mut self, audio: &mut AudioData) {
+        let mut length = None;
+        while let Ok(received) = self.socket.recv(&mut self.buffer) {
+            length = Some(received);
+        }
+        if let Some(length) = length {
+            audio.publish(&self.buffer[..length]);
+        }
+    }
+}
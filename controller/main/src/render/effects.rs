@@ -10,6 +10,16 @@ mod music_visualizer;
 
 mod flashing_color;
 mod solid_color;
+mod white;
+mod expression_color;
+
+mod spatial_gradient;
+mod plane_wave;
+mod fire;
+mod twinkle;
+mod comet;
+mod image;
+mod clock;
 
 mod websocket_input;
 
@@ -29,10 +39,21 @@ pub use music_visualizer::MusicVisualizerEffect;
 pub use rotate::RotateEffect;
 pub use flashing_color::FlashingColorEffect;
 pub use solid_color::SolidColorEffect;
+pub use white::WhiteEffect;
+pub use expression_color::ExpressionColorEffect;
+pub use spatial_gradient::{LinearGradientEffect, RadialGradientEffect};
+pub use plane_wave::PlaneWaveEffect;
+pub use fire::{FireBase, FireEffect};
+pub use twinkle::TwinkleEffect;
+pub use comet::{CometEffect, CometMode, CometPath};
+pub use image::ImageEffect;
+pub use clock::{ClockEffect, ClockMarker};
 pub use websocket_input::WebsocketInputEffect;
-pub use node_editor::NodeEditorEffect;
+pub use node_editor::{NodeEditorEffect, NodeGroup};
 
 pub use temporary::duration::DurationTemporaryEffect;
+pub use temporary::ripple::RippleTemporaryEffect;
+pub use temporary::sunrise::SunriseTemporaryEffect;
 pub use temporary::TemporaryEffectCompositor;
 
 /// Context used while rendering that can be changed as state
@@ -46,8 +67,24 @@ pub struct RenderContext {
 }
 
 impl RenderContext {
-    pub fn expression_context(&self) -> ExpressionContext {
-        return ExpressionContext { current_time: self.time }
+    /// Creates the context for expressions that are computed once per frame.
+    pub fn expression_context<'a>(&self, render_info: &'a RenderInfo) -> ExpressionContext<'a> {
+        return self.pixel_expression_context(0, render_info);
+    }
+
+    /// Creates the context for expressions that are computed separately for every pixel.
+    pub fn pixel_expression_context<'a>(&self, pixel: u32, render_info: &'a RenderInfo) -> ExpressionContext<'a> {
+        let location = render_info.pixel_locations.get(pixel as usize);
+        return ExpressionContext {
+            current_time: self.time,
+            frame: render_info.frames,
+            pixel,
+            position: pixel as f64 / self.pixels.max(1) as f64,
+            x: location.map_or(0., |location| location.x as f64),
+            y: location.map_or(0., |location| location.y as f64),
+            variables: &render_info.variables,
+            audio: &render_info.audio
+        }
     }
 }
 
@@ -56,6 +93,11 @@ impl RenderContext {
 #[enum_dispatch]
 pub trait Effect {
     fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame;
+
+    /// Prepares the effect to be rendered, like folding constant expressions. This is called when an effect
+    /// is loaded for rendering rather than when it's saved, so presets keep the form they were written in.
+    /// Effects that contain expressions or other effects should forward this to them.
+    fn optimize(&mut self) {}
 }
 
 /// A temporary effect is a type of effect that determines when it should be removed.
@@ -84,6 +126,16 @@ pub enum AnyEffect {
     Rotate(RotateEffect),
     FlashingColor(FlashingColorEffect),
     SolidColor(SolidColorEffect),
+    White(WhiteEffect),
+    ExpressionColor(ExpressionColorEffect),
+    LinearGradient(LinearGradientEffect),
+    RadialGradient(RadialGradientEffect),
+    PlaneWave(PlaneWaveEffect),
+    Fire(FireEffect),
+    Twinkle(TwinkleEffect),
+    Comet(CometEffect),
+    Image(ImageEffect),
+    Clock(ClockEffect),
     WebsocketInput(WebsocketInputEffect),
     NodeEditorEffect(NodeEditorEffect)
 }
@@ -96,4 +148,6 @@ pub enum AnyEffect {
 #[enum_dispatch(TemporaryEffect, Effect)]
 pub enum AnyTemporaryEffect {
     TemporaryEffectWrapper(DurationTemporaryEffect),
+    Ripple(RippleTemporaryEffect),
+    Sunrise(SunriseTemporaryEffect),
 }
\ No newline at end of file
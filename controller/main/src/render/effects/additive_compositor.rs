@@ -47,4 +47,10 @@ impl Effect for AdditiveCompositorEffect {
 
         final_frame
     }
+
+    fn optimize(&mut self) {
+        for effect in &mut self.effects {
+            effect.optimize();
+        }
+    }
 }
\ No newline at end of file
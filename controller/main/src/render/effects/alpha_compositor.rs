@@ -56,4 +56,10 @@ impl Effect for AlphaCompositorEffect {
             .collect::<Vec<_>>();
         AlphaCompositorEffect::composite(effects, context, render_info)
     }
+
+    fn optimize(&mut self) {
+        for effect in &mut self.effects {
+            effect.optimize();
+        }
+    }
 }
\ No newline at end of file
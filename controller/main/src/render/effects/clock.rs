@@ -0,0 +1,126 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::{AnyColorExpression, ColorExpression}, frame::{Frame, PixelColor}, local_time::LocalTime, spatial_map::get_bounds}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// A marker on the clock, like a hand or the hour ticks.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ClockMarker {
+    pub color: AnyColorExpression,
+    /// The width of the marker around the room, in degrees. Markers fade out toward their edges.
+    pub width: f64
+}
+
+impl ClockMarker {
+    #[allow(unused)]
+    pub fn new(color: impl Into<AnyColorExpression>, width: f64) -> ClockMarker {
+        ClockMarker {
+            color: color.into(),
+            width
+        }
+    }
+
+    /// How strongly a pixel at `angle` shows a marker at `marker_angle`, from 0 to 1.
+    fn brightness(&self, angle: f64, marker_angle: f64) -> f64 {
+        let distance = ((angle - marker_angle + 180.).rem_euclid(360.) - 180.).abs();
+        (1. - distance / (self.width / 2.)).max(0.)
+    }
+}
+
+/// Shows the time of day around the room like the face of a clock, with the hands placed by their angle from the center of the room.
+/// Uses the controller's local time zone.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ClockEffect {
+    hour: Option<ClockMarker>,
+    minute: Option<ClockMarker>,
+    second: Option<ClockMarker>,
+    /// Markers at every hour, drawn under the hands.
+    ticks: Option<ClockMarker>,
+    /// The direction of 12 o'clock from the center of the room, in degrees. 0 points along the x axis and 90 along the y axis.
+    rotation: f64,
+    /// Whether the hands move clockwise when looking down at the room with the y axis pointing up.
+    clockwise: bool
+}
+
+impl ClockEffect {
+    /// Creates a new clock effect.
+    #[allow(unused)]
+    pub fn new(
+        hour: Option<ClockMarker>,
+        minute: Option<ClockMarker>,
+        second: Option<ClockMarker>,
+        ticks: Option<ClockMarker>,
+        rotation: f64,
+        clockwise: bool
+    ) -> AnyEffect {
+        ClockEffect {
+            hour,
+            minute,
+            second,
+            ticks,
+            rotation,
+            clockwise
+        }.into()
+    }
+}
+
+/// Draws a marker over a pixel, blending with what's already there.
+fn draw(pixel: &mut PixelColor, color: &PixelColor, brightness: f64) {
+    if brightness <= 0. {
+        return;
+    }
+    *pixel = if pixel.alpha <= 0. {
+        color.with_alpha(color.alpha * brightness)
+    } else {
+        pixel.lerp(color, brightness)
+    };
+}
+
+impl Effect for ClockEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let hours = LocalTime::now().hours();
+        let expression_context = context.expression_context(render_info);
+        // Markers are drawn from bottom to top, along with the angle of each of their copies
+        let markers = [
+            (&mut self.ticks, (0..12).map(|hour| hour as f64 * 30.).collect::<Vec<_>>()),
+            (&mut self.hour, vec![(hours % 12.) * 30.]),
+            (&mut self.minute, vec![(hours * 60. % 60.) * 6.]),
+            (&mut self.second, vec![(hours * 3600. % 60.).floor() * 6.])
+        ].into_iter()
+            .filter_map(|(marker, angles)| marker.as_mut().map(|marker| {
+                let color = marker.color.compute(&expression_context);
+                (&*marker, color, angles)
+            }))
+            .collect::<Vec<_>>();
+
+        let locations = render_info.pixel_locations.iter().take(context.pixels as usize);
+        let (min, max) = get_bounds(locations.clone());
+        let center_x = (min.x + max.x) as f64 / 2.;
+        let center_y = (min.y + max.y) as f64 / 2.;
+
+        for (i, location) in locations.enumerate() {
+            let direction = (location.y as f64 - center_y).atan2(location.x as f64 - center_x).to_degrees();
+            // The angle of the pixel on the clock face, with 0 at 12 o'clock
+            let angle = if self.clockwise { self.rotation - direction } else { direction - self.rotation };
+
+            let mut pixel = PixelColor::new(0, 0, 0, 0.);
+            for (marker, color, angles) in &markers {
+                let brightness = angles.iter().map(|marker_angle| marker.brightness(angle, *marker_angle)).fold(0., f64::max);
+                draw(&mut pixel, color, brightness);
+            }
+            frame.set_pixel(i as u32, pixel);
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        for marker in [&mut self.hour, &mut self.minute, &mut self.second, &mut self.ticks].into_iter().flatten() {
+            marker.color.fold_constants();
+        }
+    }
+}
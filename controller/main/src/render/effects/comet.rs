@@ -0,0 +1,157 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::Animatable, frame::Frame, gradient::Gradient, spatial_map::Location}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// The most comets an effect can have, since every comet is checked for every pixel.
+static MAX_HEADS: u32 = 32;
+
+/// What the comets do when they reach the end of their path.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum CometMode {
+    /// Comets continue from the start of the path.
+    Wrap,
+    /// Comets turn around, and their tails fold back behind them.
+    Bounce
+}
+
+/// The path the comets travel along.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum CometPath {
+    /// Along the strip in pixel order. Distances are in pixels.
+    Strip,
+    /// Along the room's perimeter through the pixel locations, so comets move at the same speed
+    /// regardless of pixel density. Distances are in meters.
+    Perimeter
+}
+
+/// One or more comets with exponentially fading tails that chase each other along the strip.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct CometEffect {
+    /// How far the comets have travelled. Accumulated every frame so changes in speed don't make them jump.
+    #[serde(skip)]
+    travelled: f64,
+    /// The number of comets, evenly spaced along the path, up to 32.
+    heads: u32,
+    /// How fast the comets move, in pixels or meters per second depending on the path. Negative speeds move backward.
+    speed: Animatable<f64>,
+    /// The length of each tail, in pixels or meters depending on the path.
+    tail_length: Animatable<f64>,
+    /// The colors of each comet, from 0 at the head to 1 at the end of the tail.
+    colors: Gradient,
+    mode: CometMode,
+    path: CometPath
+}
+
+impl CometEffect {
+    /// Creates a new comet effect.
+    #[allow(unused)]
+    pub fn new(
+        heads: u32,
+        speed: impl Into<Animatable<f64>>,
+        tail_length: impl Into<Animatable<f64>>,
+        colors: Gradient,
+        mode: CometMode,
+        path: CometPath
+    ) -> AnyEffect {
+        CometEffect {
+            travelled: 0.,
+            heads,
+            speed: speed.into(),
+            tail_length: tail_length.into(),
+            colors,
+            mode,
+            path
+        }.into()
+    }
+
+    /// The position of every pixel along the path and the length of the path.
+    /// Wrapping paths include the distance from the last pixel back to the first.
+    fn path_positions(&self, locations: &[Location]) -> (Vec<f64>, f64) {
+        let closed = self.mode == CometMode::Wrap;
+        match self.path {
+            CometPath::Strip => {
+                let length = if closed { locations.len() } else { locations.len().saturating_sub(1) };
+                ((0..locations.len()).map(|i| i as f64).collect(), length as f64)
+            }
+            CometPath::Perimeter => {
+                let distance = |a: &Location, b: &Location| (b.x as f64 - a.x as f64).hypot(b.y as f64 - a.y as f64);
+                let mut positions = Vec::with_capacity(locations.len());
+                let mut length = 0.;
+                for (i, location) in locations.iter().enumerate() {
+                    if i > 0 {
+                        length += distance(&locations[i - 1], location);
+                    }
+                    positions.push(length);
+                }
+                if let (true, Some(first), Some(last)) = (closed, locations.first(), locations.last()) {
+                    length += distance(last, first);
+                }
+                (positions, length)
+            }
+        }
+    }
+}
+
+impl Effect for CometEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let expression_context = context.expression_context(render_info);
+        let speed = self.speed.compute(&expression_context);
+        self.travelled += speed * context.delta.as_secs_f64();
+        let tail_length = self.tail_length.compute(&expression_context);
+        let colors = self.colors.compute(&expression_context);
+
+        let locations = &render_info.pixel_locations[..(context.pixels as usize).min(render_info.pixel_locations.len())];
+        let (positions, length) = self.path_positions(locations);
+        let heads = self.heads.min(MAX_HEADS);
+        if heads == 0 || tail_length <= 0. || length <= 0. {
+            return frame;
+        }
+
+        // Bouncing comets go back and forth, so one trip along the path is twice its length
+        let cycle = match self.mode {
+            CometMode::Wrap => length,
+            CometMode::Bounce => length * 2.
+        };
+        let spacing = cycle / heads as f64;
+
+        for (i, position) in positions.iter().enumerate() {
+            // The distance behind the nearest comet that has passed this pixel
+            let behind = (0..heads).map(|head| {
+                let head = self.travelled + head as f64 * spacing;
+                match self.mode {
+                    CometMode::Wrap if speed < 0. => (position - head).rem_euclid(cycle),
+                    CometMode::Wrap => (head - position).rem_euclid(cycle),
+                    // A bouncing comet passes every pixel once on the way out and once on the way back.
+                    // Going back and forth looks the same in reverse, so comets moving backward are mirrored.
+                    CometMode::Bounce => {
+                        let head = if speed < 0. { -head } else { head };
+                        (head - position).rem_euclid(cycle).min((head + position).rem_euclid(cycle))
+                    }
+                }
+            }).fold(f64::INFINITY, f64::min);
+
+            if behind <= tail_length {
+                let t = behind / tail_length;
+                // Fade to 1% brightness at the end of the tail
+                let brightness = 0.01_f64.powf(t);
+                let color = colors.sample(t);
+                frame.set_pixel(i as u32, color.with_alpha(color.alpha * brightness));
+            }
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.speed.fold_constants();
+        self.tail_length.fold_constants();
+        self.colors.fold_constants();
+    }
+}
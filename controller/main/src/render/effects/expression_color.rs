@@ -0,0 +1,49 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::{AnyColorExpression, AnyExpression, ColorExpression, Expression}, frame::Frame}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ExpressionColorEffect {
+    /// The color expression computed for every pixel to get its color.
+    color: AnyColorExpression,
+    /// The alpha of every pixel, from 0 to 1. Overrides the alpha of the color if set.
+    alpha: Option<Box<AnyExpression>>
+}
+
+impl ExpressionColorEffect {
+    /// Creates a new expression color effect.
+    #[allow(unused)]
+    pub fn new(color: AnyColorExpression, alpha: Option<Box<AnyExpression>>) -> AnyEffect {
+        ExpressionColorEffect {
+            color,
+            alpha
+        }.into()
+    }
+}
+
+impl Effect for ExpressionColorEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        for i in 0..context.pixels {
+            let expression_context = context.pixel_expression_context(i, render_info);
+            let mut color = self.color.compute(&expression_context);
+            if let Some(alpha) = &mut self.alpha {
+                color.alpha = alpha.compute(&expression_context).clamp(0., 1.);
+            }
+            frame.set_pixel(i, color);
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.color.fold_constants();
+        if let Some(alpha) = &mut self.alpha {
+            alpha.fold_constants();
+        }
+    }
+}
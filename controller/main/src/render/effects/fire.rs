@@ -0,0 +1,126 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::Animatable, frame::Frame, gradient::Gradient, random::SeededRng}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// Which end of each span in the spatial map the flames rise from.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum FireBase {
+    Start,
+    End,
+    /// Flames rise from both ends and meet in the middle.
+    Both
+}
+
+/// A procedural fire simulation. Heat is simulated separately along every span in the spatial map:
+/// every frame, each pixel cools down, heat drifts up away from the base, and new sparks randomly ignite near the base.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct FireEffect {
+    /// How quickly the flames cool, from 0 to 1. Higher values make shorter flames.
+    cooling: Animatable<f64>,
+    /// The chance of a new spark near the base of each flame every frame, from 0 to 1.
+    sparking: Animatable<f64>,
+    /// The colors heat is mapped to, from coldest at 0 to hottest at 1.
+    palette: Gradient,
+    /// Which end of each span the flames rise from.
+    base: FireBase,
+
+    /// The pixels of every flame, in order from its base upward, and their heat from 0 to 1.
+    #[serde(skip)]
+    flames: Vec<(Vec<u32>, Vec<f64>)>,
+    #[serde(skip)]
+    rng: Option<SeededRng>
+}
+
+impl FireEffect {
+    /// Creates a new fire effect.
+    #[allow(unused)]
+    pub fn new(cooling: impl Into<Animatable<f64>>, sparking: impl Into<Animatable<f64>>, palette: Gradient, base: FireBase) -> AnyEffect {
+        FireEffect {
+            cooling: cooling.into(),
+            sparking: sparking.into(),
+            palette,
+            base,
+            flames: vec![],
+            rng: None
+        }.into()
+    }
+
+    /// Splits the spans of the spatial map into flames, each starting at its base.
+    fn create_flames(&self, segments: &[Vec<u32>]) -> Vec<(Vec<u32>, Vec<f64>)> {
+        let mut flames = vec![];
+        for segment in segments {
+            match self.base {
+                FireBase::Start => flames.push(segment.clone()),
+                FireBase::End => flames.push(segment.iter().rev().copied().collect()),
+                FireBase::Both => {
+                    let (start, end) = segment.split_at(segment.len() / 2);
+                    flames.push(start.to_vec());
+                    flames.push(end.iter().rev().copied().collect());
+                }
+            }
+        }
+        flames.into_iter().map(|pixels| {
+            let heat = vec![0.; pixels.len()];
+            (pixels, heat)
+        }).collect()
+    }
+}
+
+impl Effect for FireEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        if self.flames.is_empty() {
+            self.flames = self.create_flames(&render_info.pixel_segments);
+        }
+
+        let expression_context = context.expression_context(render_info);
+        let cooling = self.cooling.compute(&expression_context).clamp(0., 1.);
+        let sparking = self.sparking.compute(&expression_context).clamp(0., 1.);
+        let palette = self.palette.compute(&expression_context);
+
+        let rng = self.rng.get_or_insert_with(|| SeededRng::new(0));
+        for (pixels, heat) in &mut self.flames {
+            let length = heat.len();
+            if length == 0 {
+                continue;
+            }
+
+            // Cool every pixel a little; shorter flames cool faster so they look similar
+            let max_cooling = cooling * 10. / length as f64 + 0.008;
+            for value in heat.iter_mut() {
+                *value = (*value - rng.next_f64() * max_cooling).max(0.);
+            }
+
+            // Heat drifts up and diffuses
+            for i in (2..length).rev() {
+                heat[i] = (heat[i - 1] + heat[i - 2] * 2.) / 3.;
+            }
+
+            // Randomly ignite new sparks near the base
+            if rng.next_f64() < sparking {
+                let spark_zone = (length / 8).clamp(1, 7);
+                let i = (rng.next_f64() * spark_zone as f64) as usize;
+                heat[i] = (heat[i] + 0.63 + rng.next_f64() * 0.37).min(1.);
+            }
+
+            for (pixel, value) in pixels.iter().zip(heat.iter()) {
+                if *pixel < context.pixels {
+                    frame.set_pixel(*pixel, palette.sample(*value));
+                }
+            }
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.cooling.fold_constants();
+        self.sparking.fold_constants();
+        self.palette.fold_constants();
+    }
+}
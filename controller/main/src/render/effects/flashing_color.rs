@@ -3,42 +3,48 @@ use std::f64::consts::PI;
 use reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
-use crate::{render::frame::{self, PixelColor}, RenderInfo};
+use crate::{render::{expressions::{Animatable, AnyColorExpression, ColorExpression}, frame}, RenderInfo};
 
 use super::{AnyEffect, Effect, RenderContext};
 
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
 pub struct FlashingColorEffect {
+    /// The number of flashes so far. Accumulated every frame so changes in speed don't make the flashing jump.
     #[serde(skip)]
-    time: f64,
+    phase: f64,
     /// The offset of the flashing effect, in seconds.
-    offset: f64,
+    offset: Animatable<f64>,
     /// The speed of the flashing effect, in Hz.
-    speed: f64,
+    speed: Animatable<f64>,
     /// The color of the first flashing color.
-    color_a: PixelColor,
+    color_a: AnyColorExpression,
     /// The color of the second flashing color.
-    color_b: PixelColor
+    color_b: AnyColorExpression
 }
 
 impl FlashingColorEffect {
     /// Creates a new flashing color effect with the specified speed and colors.
     /// Speed is in Hz.
     #[allow(unused)]
-    pub fn new(speed: f64, offset: f64, color_a: PixelColor, color_b: PixelColor) -> AnyEffect {
+    pub fn new(speed: impl Into<Animatable<f64>>, offset: impl Into<Animatable<f64>>, color_a: impl Into<AnyColorExpression>, color_b: impl Into<AnyColorExpression>) -> AnyEffect {
         Self {
-            time: 0., offset, speed, color_a, color_b
+            phase: 0., offset: offset.into(), speed: speed.into(), color_a: color_a.into(), color_b: color_b.into()
         }.into()
     }
 }
 
 impl Effect for FlashingColorEffect {
-    fn render(&mut self, context: RenderContext, _render_info: &mut RenderInfo) -> frame::Frame {
-        self.time += context.delta.as_secs_f64();
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> frame::Frame {
+        let expression_context = context.expression_context(render_info);
+        let speed = self.speed.compute(&expression_context);
+        let offset = self.offset.compute(&expression_context);
+        self.phase += context.delta.as_secs_f64() * speed;
 
         let mut frame: frame::Frame = frame::Frame::empty(context.pixels);
 
-        let color = self.color_a.lerp(&self.color_b, (self.time * self.speed * 2. * PI).sin() * 0.5 + 0.5);
+        let color_a = self.color_a.compute(&expression_context);
+        let color_b = self.color_b.compute(&expression_context);
+        let color = color_a.lerp(&color_b, ((self.phase + offset * speed) * 2. * PI).sin() * 0.5 + 0.5);
 
         for pixel in 0..context.pixels {
             frame.set_pixel(pixel, color.clone());
@@ -46,4 +52,11 @@ impl Effect for FlashingColorEffect {
 
         frame
     }
+
+    fn optimize(&mut self) {
+        self.offset.fold_constants();
+        self.speed.fold_constants();
+        self.color_a.fold_constants();
+        self.color_b.fold_constants();
+    }
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::Animatable, frame::Frame, image::Image, spatial_map::get_bounds}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// Shows one row of the image at a time, stretched across the strip, moving down the image over time.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct RowsImageMapping {
+    /// How many rows are shown every second.
+    rows_per_second: f64
+}
+
+/// How an image is mapped onto the pixels.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum ImageMapping {
+    Rows(RowsImageMapping),
+    /// Projects the image onto the room from above, stretched to fit the pixel locations,
+    /// with the top of the image at the largest y.
+    Project
+}
+
+/// Plays an image or animated GIF on the strip.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ImageEffect {
+    /// The name of a GIF uploaded to the controller with `PUT /api/image/:name`.
+    image: String,
+    mapping: ImageMapping,
+    /// How fast the image plays. 1 is the speed the GIF was made for.
+    speed: Animatable<f64>,
+    /// Whether the image starts over once it ends. Otherwise, it stays on the last frame.
+    looping: bool,
+
+    /// How far the image has played, in seconds at normal speed.
+    #[serde(skip)]
+    playback_time: f64,
+    /// The decoded image, or why it couldn't be loaded. Loaded when the effect is optimized, so decoding
+    /// doesn't hold up the render thread.
+    #[serde(skip)]
+    loaded: Option<Result<Arc<Image>, String>>
+}
+
+impl ImageEffect {
+    /// Creates a new image effect.
+    #[allow(unused)]
+    pub fn new(image: String, mapping: ImageMapping, speed: impl Into<Animatable<f64>>, looping: bool) -> AnyEffect {
+        ImageEffect {
+            image,
+            mapping,
+            speed: speed.into(),
+            looping,
+            playback_time: 0.,
+            loaded: None
+        }.into()
+    }
+}
+
+impl Effect for ImageEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let speed = self.speed.compute(&context.expression_context(render_info));
+        self.playback_time += speed.max(0.) * context.delta.as_secs_f64();
+
+        let image = match &self.loaded {
+            Some(Ok(image)) => image,
+            Some(Err(e)) => {
+                render_info.debug_text = format!("Failed to load image {}: {}", self.image, e);
+                return frame;
+            }
+            None => return frame
+        };
+        let image_frame = image.frame_at(self.playback_time, self.looping);
+
+        match &self.mapping {
+            ImageMapping::Rows(mapping) => {
+                let rows_shown = (self.playback_time * mapping.rows_per_second).max(0.) as usize;
+                let row = if self.looping { rows_shown % image.height } else { rows_shown.min(image.height - 1) };
+                for i in 0..context.pixels {
+                    let x = (i as usize * image.width) / context.pixels as usize;
+                    frame.set_pixel(i, image.get_pixel(image_frame, x, row));
+                }
+            }
+            ImageMapping::Project => {
+                let locations = render_info.pixel_locations.iter().take(context.pixels as usize);
+                let (min, max) = get_bounds(locations.clone());
+                let room_width = (max.x - min.x).max(f32::EPSILON);
+                let room_height = (max.y - min.y).max(f32::EPSILON);
+
+                for (i, location) in locations.enumerate() {
+                    let u = (location.x - min.x) / room_width;
+                    let v = (max.y - location.y) / room_height;
+                    let x = ((u * image.width as f32) as usize).min(image.width - 1);
+                    let y = ((v * image.height as f32) as usize).min(image.height - 1);
+                    frame.set_pixel(i as u32, image.get_pixel(image_frame, x, y));
+                }
+            }
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.speed.fold_constants();
+        if self.loaded.is_none() {
+            let image = Image::load(&self.image).map(Arc::new);
+            if let Err(e) = &image {
+                println!("Failed to load image {}: {}", self.image, e);
+            }
+            self.loaded = Some(image);
+        }
+    }
+}
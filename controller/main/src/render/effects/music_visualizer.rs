@@ -1,35 +1,21 @@
-use std::{cmp::min, net::{Ipv4Addr, SocketAddr, UdpSocket}};
+use std::cmp::min;
 
-use color_space::Hsl;
 use reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
-use crate::{render::frame::{Frame, PixelColor}, RenderInfo};
+use crate::{render::{frame::{Frame, PixelColor}, gradient::Gradient}, RenderInfo};
 
 use super::{AnyEffect, Effect, RenderContext};
 
 static PACKET_FROP_FRAMES: usize = 500;
 
-/// The music visualizer effect runs a TCP socket server that listens for
-/// audio data from the music visualizer client. Then, it renders the audio
-/// data as a visualizer.
-#[derive(Reflect, Debug, Serialize, Deserialize)]
+/// The music visualizer effect renders the audio data received from the
+/// music visualizer client as a visualizer.
+#[derive(Reflect, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MusicVisualizerEffect {
-    /// The UDP listener that listens for audio data from the music visualizer client.
-    #[serde(rename = "port", deserialize_with = "deserialize_udp_socket", serialize_with = "serialize_udp_socket")]
-    #[reflect(as_type = "u16")]
-    listener: UdpSocket,
-
-    /// The buffer that stores the audio data
-    #[serde(skip)]
-    audio_buffer: Vec<f32>,
-
-    /// The time at which the last audio data was received
-    /// If no audio data has been received in a while, the visualizer will
-    /// display a pulsing red color
-    #[serde(skip)]
-    data_last_received: Option<std::time::Instant>,
+    /// The colors along the strip, with the lightness set by the audio level. If not set, a rainbow is used.
+    gradient: Option<Gradient>,
 
     /// Used to calculate last frames' packet drop rate
     #[cfg(debug_assertions)]
@@ -37,53 +23,16 @@ pub struct MusicVisualizerEffect {
     packet_receive_frames: [bool; PACKET_FROP_FRAMES],
 }
 
-impl Clone for MusicVisualizerEffect {
-    fn clone(&self) -> Self {
-        Self {
-            listener: self.listener.try_clone().unwrap(),
-            audio_buffer: self.audio_buffer.clone(),
-            data_last_received: self.data_last_received,
-            #[cfg(debug_assertions)]
-            packet_receive_frames: self.packet_receive_frames.clone(),
-        }
-    }
-}
-
 fn default_packet_receive_frames() -> [bool; PACKET_FROP_FRAMES] {
     [false; PACKET_FROP_FRAMES]
 }
 
-/// Deserialize a UDP socket from a port number
-fn deserialize_udp_socket<'de, D>(deserializer: D) -> Result<UdpSocket, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let port = u16::deserialize(deserializer)?;
-    let listener = UdpSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, port))).unwrap();
-    listener.set_nonblocking(true).unwrap();
-    Ok(listener)
-}
-
-fn serialize_udp_socket<S>(listener: &UdpSocket, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    listener.local_addr().unwrap().port().serialize(serializer)
-}
-
 impl MusicVisualizerEffect {
-    /// Creates a new music visualizer effect that listens on the specified port.
+    /// Creates a new music visualizer effect.
     #[allow(unused)]
-    pub fn new(port: u16) -> AnyEffect {
-        let listener = UdpSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, port))).unwrap();
-        listener.set_nonblocking(true).unwrap();
-
-        println!("Music visualizer effect listening on port {}", port);
-        
+    pub fn new(gradient: Option<Gradient>) -> AnyEffect {
         Self {
-            listener,
-            audio_buffer: vec![],
-            data_last_received: None,
+            gradient,
 
             #[cfg(debug_assertions)]
             packet_receive_frames: [false; PACKET_FROP_FRAMES],
@@ -96,33 +45,11 @@ impl Effect for MusicVisualizerEffect {
         static BLOCK_SIZE: usize = 4;
         let pixels = context.pixels;
 
-        // Read audio data from the client
-        let mut looped = false;
-        let mut audio_data = vec![0; pixels as usize / BLOCK_SIZE];
-        while self.listener.peek_from(&mut [0; 1]).is_ok() {
-            looped = true;
-            self.listener.recv(&mut audio_data).unwrap();
-        }
-        
-        if looped {
-            self.audio_buffer = audio_data.iter().map(|&x| x as f32).collect();
-            self.data_last_received = Some(std::time::Instant::now());
-
-            #[cfg(debug_assertions)] {
-                self.packet_receive_frames[info.frames % PACKET_FROP_FRAMES] = true;
-            }
-        } else {
-            // No audio data is available, so slowly fade out the audio data to make it feel slightly more responsive
-            for i in 0..self.audio_buffer.len() {
-                self.audio_buffer[i] *= 0.5_f32.powf(context.delta.as_secs_f32());
-            }
-
-            #[cfg(debug_assertions)] {
-                self.packet_receive_frames[info.frames % PACKET_FROP_FRAMES] = false;
-            }
+        #[cfg(debug_assertions)] {
+            self.packet_receive_frames[info.frames % PACKET_FROP_FRAMES] = info.audio.received;
         }
 
-        if self.data_last_received.is_none() || self.data_last_received.unwrap().elapsed().as_secs() > 2 {
+        if info.audio.last_received.is_none_or(|last_received| last_received.elapsed().as_secs() > 2) {
             // If there are no incoming connections, return pulsing red
             let mut frame = Frame::empty(pixels);
             let color = PixelColor::new(
@@ -142,7 +69,7 @@ impl Effect for MusicVisualizerEffect {
 
 
         // Linearly interpolate the audio data
-        let audio_data = &self.audio_buffer;
+        let audio_data = &info.audio.spectrum;
 
         let mut audio_data_interpolated = vec![0; pixels as usize];
         
@@ -157,11 +84,20 @@ impl Effect for MusicVisualizerEffect {
         }
 
         // Render the visualizer
+        let gradient = self.gradient.as_mut().map(|gradient| gradient.compute(&context.expression_context(info)));
         let mut frame = Frame::empty(pixels);
         for i in 0..pixels as usize {
-            let hue = i as f64 / pixels as f64 * 360.;
+            let position = i as f64 / pixels as f64;
             let lightness = audio_data_interpolated[i] as f64 / 255.;
-            let color = Hsl::new(hue, 0.5, lightness as f64).into();
+            let color = match &gradient {
+                Some(gradient) => {
+                    let color = gradient.sample(position);
+                    let alpha = color.alpha;
+                    let (hue, saturation, _) = color.to_hsl();
+                    PixelColor::from_hsl(hue, saturation, lightness, alpha)
+                }
+                None => PixelColor::from_hsl(position * 360., 0.5, lightness, 1.)
+            };
             
             frame.set_pixel(i as u32, color);
         }
@@ -177,4 +113,10 @@ impl Effect for MusicVisualizerEffect {
 
         return frame;
     }
+
+    fn optimize(&mut self) {
+        if let Some(gradient) = &mut self.gradient {
+            gradient.fold_constants();
+        }
+    }
 }
\ No newline at end of file
@@ -1,43 +1,63 @@
 use std::{collections::HashMap, fmt::Debug};
-use node::{Node, NodeID};
+use compiler::{CachedGraph, CompiledGraph};
+use node::{Node, NodeConnection, NodeContext, NodeID, NodeParameter};
+use registry::NODE_REGISTRY;
 use reflection::Reflect;
-use serde::{Deserialize, Serialize};
-use types::{TypeInfo};
+use serde::{de::Error, Deserialize, Serialize};
+use types::{AnyType, TypeInfo};
 use crate::{render::frame::Frame, RenderInfo};
 use super::{Effect, RenderContext};
 
+pub use group::NodeGroup;
+
 #[macro_use]
 mod types;
 mod nodes;
 mod node;
+mod compiler;
+mod group;
 #[macro_use]
 mod registry;
 
 struct NodeData {
-    instance: Box<dyn Node>
+    /// The name the node type is registered under.
+    node_type: String,
+    instance: Box<dyn Node>,
+    /// The connection to each input port. Unconnected inputs use a default value.
+    inputs: Vec<Option<NodeConnection>>
 }
 
 impl Clone for NodeData {
     fn clone(&self) -> Self {
         NodeData {
-            instance: dyn_clone::clone_box(&*self.instance)
+            node_type: self.node_type.clone(),
+            instance: dyn_clone::clone_box(&*self.instance),
+            inputs: self.inputs.clone()
         }
     }
 }
 
+/// The serialized form of a node. Nodes are recreated from the registry by their type name.
+#[derive(Reflect, Serialize, Deserialize)]
+struct SerializedNodeData {
+    #[serde(rename = "type")]
+    node_type: String,
+    inputs: Vec<Option<NodeConnection>>,
+    parameters: HashMap<String, NodeParameter>
+}
+
 impl Reflect for NodeData {
     fn ts_definition() -> String {
-        todo!()
+        SerializedNodeData::ts_definition()
     }
     
     fn schema() -> reflection::schema::Schema {
-        todo!()
+        SerializedNodeData::schema()
     }
     
-    fn visit_dependencies(_: &mut impl reflection::TypeVisitor) where Self: 'static {
-        todo!()
+    fn visit_dependencies(visitor: &mut impl reflection::TypeVisitor) where Self: 'static {
+        SerializedNodeData::visit_dependencies(visitor)
     }
-    // TODO...
 }
 
 impl Serialize for NodeData {
@@ -45,7 +65,11 @@ impl Serialize for NodeData {
     where
         S: serde::Serializer,
     {
-        todo!()
+        SerializedNodeData {
+            node_type: self.node_type.clone(),
+            inputs: self.inputs.clone(),
+            parameters: self.instance.parameters()
+        }.serialize(serializer)
     }
 }
 
@@ -54,7 +78,24 @@ impl<'de> Deserialize<'de> for NodeData {
     where
         D: serde::Deserializer<'de>,
     {
-        todo!()
+        let data = SerializedNodeData::deserialize(deserializer)?;
+
+        let mut instance = match NODE_REGISTRY.lock().borrow().get_node(&data.node_type) {
+            Some(node) => dyn_clone::clone_box(&**node),
+            None => return Err(D::Error::custom(format!("Unknown node type {}", data.node_type)))
+        };
+        for (name, value) in data.parameters {
+            instance.set_parameter(&name, value).map_err(D::Error::custom)?;
+        }
+
+        let mut inputs = data.inputs;
+        inputs.resize(instance.input_ports().len(), None);
+
+        Ok(NodeData {
+            node_type: data.node_type,
+            instance,
+            inputs
+        })
     }
 }
 
@@ -71,12 +112,61 @@ impl Debug for NodeData {
 /// calculations for every pixel in the frame.
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
 pub struct NodeEditorEffect {
-    nodes: HashMap<NodeID, NodeData>
+    nodes: HashMap<NodeID, NodeData>,
+    /// The node output that is displayed. A color output is evaluated for every pixel, while a frame output is used directly.
+    output: Option<NodeConnection>,
+    /// The graph compiled for rendering, or why it couldn't be compiled. Compiled on the first render after the effect is loaded.
+    #[serde(skip)]
+    compiled: Option<CachedGraph>
+}
+
+impl NodeEditorEffect {
+    fn render_graph(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Result<Frame, String> {
+        let mut frame = Frame::empty(context.pixels);
+        let output = match &self.output {
+            Some(output) => output.clone(),
+            None => return Ok(frame)
+        };
+
+        if self.compiled.as_ref().is_none_or(|cached| !cached.is_for(context.pixels, false)) {
+            let graph = CompiledGraph::compile(&self.nodes, &[output], context.pixels, false);
+            self.compiled = Some(CachedGraph::new(context.pixels, false, graph));
+        }
+        let graph = self.compiled.as_mut().unwrap().graph()?;
+
+        let mut node_context = NodeContext {
+            render_context: context,
+            render_info,
+            pixel: 0,
+            per_pixel: false,
+            inputs_vary: false,
+            group_inputs: &[]
+        };
+        graph.run_frame(&mut node_context)?;
+
+        for pixel in 0..context.pixels {
+            node_context.pixel = pixel;
+            graph.run_pixel(&mut node_context)?;
+
+            match graph.output(0) {
+                AnyType::ColorValue(color) => frame.set_pixel(pixel, color.0.clone()),
+                AnyType::FrameValue(output_frame) => return Ok(Frame::clone(&output_frame.0)),
+                value => return Err(format!("The output must be a color or frame, not {}", value.type_name()))
+            }
+        }
+
+        Ok(frame)
+    }
 }
 
 impl Effect for NodeEditorEffect {
     fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
-        // TODO
-        Frame::empty(context.pixels)
+        match self.render_graph(context, render_info) {
+            Ok(frame) => frame,
+            Err(e) => {
+                render_info.debug_text = format!("Node graph error: {}", e);
+                Frame::empty(context.pixels)
+            }
+        }
     }
 }
\ No newline at end of file
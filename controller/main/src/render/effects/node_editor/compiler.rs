@@ -0,0 +1,342 @@
+use std::{collections::{HashMap, HashSet}, fmt::Debug, ops::Range};
+
+use super::{node::{Node, NodeConnection, NodeContext, NodeID}, types::AnyType, NodeData};
+
+/// A node graph compiled into a flat list of instructions.
+/// Every node output and unconnected input gets a preallocated register, so evaluating the graph
+/// doesn't walk the graph or allocate. Nodes that don't vary per pixel are only computed once per frame.
+pub(super) struct CompiledGraph {
+    nodes: Vec<Box<dyn Node>>,
+    frame_instructions: Vec<Instruction>,
+    pixel_instructions: Vec<Instruction>,
+    registers: Vec<AnyType>,
+    /// Scratch space the inputs of each instruction are gathered into.
+    inputs: Vec<AnyType>,
+    /// The registers holding the outputs the graph was compiled for.
+    outputs: Vec<usize>
+}
+
+#[derive(Clone)]
+struct Instruction {
+    /// The index of the node in `CompiledGraph::nodes`.
+    node: usize,
+    /// The register each input is read from.
+    inputs: Vec<usize>,
+    /// The registers the node's outputs are written to.
+    outputs: Range<usize>,
+    per_pixel: bool,
+    /// Whether any of the node's inputs vary per pixel.
+    inputs_vary: bool
+}
+
+impl Clone for CompiledGraph {
+    fn clone(&self) -> Self {
+        CompiledGraph {
+            nodes: self.nodes.iter().map(|node| dyn_clone::clone_box(&**node)).collect(),
+            frame_instructions: self.frame_instructions.clone(),
+            pixel_instructions: self.pixel_instructions.clone(),
+            registers: self.registers.clone(),
+            inputs: Vec::with_capacity(self.inputs.capacity()),
+            outputs: self.outputs.clone()
+        }
+    }
+}
+
+impl Debug for CompiledGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompiledGraph")
+            .field("frame_instructions", &self.frame_instructions.len())
+            .field("pixel_instructions", &self.pixel_instructions.len())
+            .field("registers", &self.registers.len())
+            .finish()
+    }
+}
+
+impl CompiledGraph {
+    /// Compiles the part of a graph needed to compute the given outputs.
+    /// The nodes are copied into the compiled graph, so any state they have is kept there.
+    /// `group_inputs_vary` is whether the inputs of the group being compiled vary per pixel, if the graph is in a group.
+    pub fn compile(nodes: &HashMap<NodeID, NodeData>, outputs: &[NodeConnection], pixels: u32, group_inputs_vary: bool) -> Result<CompiledGraph, String> {
+        let mut compiler = Compiler {
+            nodes,
+            pixels,
+            group_inputs_vary,
+            compiled: HashMap::new(),
+            visiting: HashSet::new(),
+            instructions: vec![],
+            program_nodes: vec![],
+            registers: vec![]
+        };
+
+        let mut output_registers = Vec::with_capacity(outputs.len());
+        for output in outputs {
+            let (instruction, _) = compiler.visit(&output.node)?;
+            output_registers.push(compiler.output_register(instruction, output)?);
+        }
+
+        // Instructions are in dependency order, and nodes computed once per frame never depend on ones that
+        // vary per pixel, so every frame instruction can run before the pixel instructions.
+        let (pixel_instructions, frame_instructions): (Vec<_>, Vec<_>) = compiler.instructions
+            .into_iter()
+            .partition(|instruction| instruction.per_pixel);
+        let max_inputs = frame_instructions.iter().chain(&pixel_instructions)
+            .map(|instruction| instruction.inputs.len())
+            .max()
+            .unwrap_or(0);
+
+        Ok(CompiledGraph {
+            nodes: compiler.program_nodes,
+            frame_instructions,
+            pixel_instructions,
+            registers: compiler.registers,
+            inputs: Vec::with_capacity(max_inputs),
+            outputs: output_registers
+        })
+    }
+
+    /// Computes the nodes that don't vary per pixel. Must be called once at the start of every frame.
+    pub fn run_frame(&mut self, context: &mut NodeContext) -> Result<(), String> {
+        Self::run(&self.frame_instructions, &mut self.nodes, &mut self.registers, &mut self.inputs, context)
+    }
+
+    /// Computes the nodes that vary per pixel for `context.pixel`.
+    pub fn run_pixel(&mut self, context: &mut NodeContext) -> Result<(), String> {
+        Self::run(&self.pixel_instructions, &mut self.nodes, &mut self.registers, &mut self.inputs, context)
+    }
+
+    /// Gets the current value of one of the outputs the graph was compiled for.
+    pub fn output(&self, index: usize) -> &AnyType {
+        &self.registers[self.outputs[index]]
+    }
+
+    fn run(
+        instructions: &[Instruction],
+        nodes: &mut [Box<dyn Node>],
+        registers: &mut [AnyType],
+        inputs: &mut Vec<AnyType>,
+        context: &mut NodeContext
+    ) -> Result<(), String> {
+        for instruction in instructions {
+            inputs.clear();
+            inputs.extend(instruction.inputs.iter().map(|&register| registers[register].clone()));
+
+            context.per_pixel = instruction.per_pixel;
+            context.inputs_vary = instruction.inputs_vary;
+            let node = &mut nodes[instruction.node];
+            node.compute(inputs, &mut registers[instruction.outputs.clone()], context)
+                .map_err(|e| format!("{}: {}", node.name(), e))?;
+        }
+        Ok(())
+    }
+}
+
+/// A compiled graph, or why it couldn't be compiled, along with what it was compiled for.
+/// Default frame values depend on the pixel count, so the graph is compiled again when it changes. Errors are kept so a graph that can't be compiled isn't compiled again every frame.
+#[derive(Clone, Debug)]
+pub(super) struct CachedGraph {
+    pixels: u32,
+    group_inputs_vary: bool,
+    graph: Result<CompiledGraph, String>
+}
+
+impl CachedGraph {
+    pub fn new(pixels: u32, group_inputs_vary: bool, graph: Result<CompiledGraph, String>) -> Self {
+        CachedGraph { pixels, group_inputs_vary, graph }
+    }
+
+    /// Whether the graph was compiled for the same pixel count and group inputs, so it doesn't need to be compiled again.
+    pub fn is_for(&self, pixels: u32, group_inputs_vary: bool) -> bool {
+        self.pixels == pixels && self.group_inputs_vary == group_inputs_vary
+    }
+
+    pub fn graph(&mut self) -> Result<&mut CompiledGraph, String> {
+        self.graph.as_mut().map_err(|e| e.clone())
+    }
+}
+
+struct Compiler<'a> {
+    nodes: &'a HashMap<NodeID, NodeData>,
+    pixels: u32,
+    group_inputs_vary: bool,
+    /// The instruction index of every node compiled so far.
+    compiled: HashMap<NodeID, usize>,
+    visiting: HashSet<NodeID>,
+    instructions: Vec<Instruction>,
+    program_nodes: Vec<Box<dyn Node>>,
+    registers: Vec<AnyType>
+}
+
+impl Compiler<'_> {
+    /// Compiles a node after the nodes it depends on. Returns its instruction index and whether it varies per pixel.
+    /// This also rejects graphs with cycles, since they could never be evaluated.
+    fn visit(&mut self, id: &NodeID) -> Result<(usize, bool), String> {
+        if let Some(&index) = self.compiled.get(id) {
+            return Ok((index, self.instructions[index].per_pixel));
+        }
+        if !self.visiting.insert(id.clone()) {
+            return Err("The node graph contains a cycle".to_string());
+        }
+
+        let node = self.nodes.get(id).ok_or_else(|| format!("Node {:?} doesn't exist", id))?;
+        let mut per_pixel = node.instance.varies_per_pixel() || (self.group_inputs_vary && node.node_type == "GroupInputNode");
+        let mut inputs_vary = false;
+
+        let mut inputs = Vec::with_capacity(node.inputs.len());
+        for (connection, port) in node.inputs.iter().zip(node.instance.input_ports()) {
+            let register = match connection {
+                Some(connection) => {
+                    let (source, source_varies) = self.visit(&connection.node)?;
+                    inputs_vary |= source_varies;
+                    let register = self.output_register(source, connection)?;
+
+                    let source_node = &self.program_nodes[self.instructions[source].node];
+                    let output_type = &source_node.output_ports()[connection.output as usize].type_info;
+                    if !output_type.can_coerce_to(&port.type_info) {
+                        return Err(format!("Can't connect a {:?} output of {} to a {:?} input", output_type, source_node.name(), port.type_info));
+                    }
+                    register
+                }
+                None => {
+                    self.registers.push(AnyType::default_for(&port.type_info, self.pixels));
+                    self.registers.len() - 1
+                }
+            };
+            inputs.push(register);
+        }
+
+        per_pixel |= inputs_vary;
+
+        let start = self.registers.len();
+        self.registers.extend(node.instance.output_ports().iter().map(|port| AnyType::default_for(&port.type_info, self.pixels)));
+
+        let mut program_node = dyn_clone::clone_box(&*node.instance);
+        program_node.optimize();
+        self.program_nodes.push(program_node);
+        self.instructions.push(Instruction {
+            node: self.program_nodes.len() - 1,
+            inputs,
+            outputs: start..self.registers.len(),
+            per_pixel,
+            inputs_vary
+        });
+
+        let index = self.instructions.len() - 1;
+        self.visiting.remove(id);
+        self.compiled.insert(id.clone(), index);
+        Ok((index, per_pixel))
+    }
+
+    /// Gets the register a connection reads from.
+    fn output_register(&self, instruction: usize, connection: &NodeConnection) -> Result<usize, String> {
+        let outputs = &self.instructions[instruction].outputs;
+        if connection.output as usize >= outputs.len() {
+            let node = &self.program_nodes[self.instructions[instruction].node];
+            return Err(format!("{} has no output {}", node.name(), connection.output));
+        }
+        Ok(outputs.start + connection.output as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+
+    use crate::render::effects::RenderContext;
+    use super::super::{node::PortInfo, types::TypeInfo};
+    use super::*;
+
+    /// A node that counts how many times it's computed and passes its input through.
+    #[derive(Clone)]
+    struct CountingNode {
+        ports: Vec<PortInfo>,
+        varies: bool,
+        computes: Arc<AtomicUsize>
+    }
+
+    impl Node for CountingNode {
+        fn name(&self) -> &'static str { "Counting" }
+        fn input_ports(&self) -> &[PortInfo] { &self.ports }
+        fn output_ports(&self) -> &[PortInfo] { &self.ports }
+        fn compute(&mut self, inputs: &[AnyType], outputs: &mut [AnyType], _context: &mut NodeContext) -> Result<(), String> {
+            self.computes.fetch_add(1, Ordering::Relaxed);
+            outputs[0] = inputs[0].clone();
+            Ok(())
+        }
+        fn varies_per_pixel(&self) -> bool {
+            self.varies
+        }
+    }
+
+    fn counting_node(varies: bool, input: Option<&NodeID>) -> (NodeData, Arc<AtomicUsize>) {
+        let computes = Arc::new(AtomicUsize::new(0));
+        let node = NodeData {
+            node_type: "Counting".to_string(),
+            instance: Box::new(CountingNode {
+                ports: vec![PortInfo { name: "Value".to_string(), type_info: TypeInfo::Float }],
+                varies,
+                computes: computes.clone()
+            }),
+            inputs: vec![input.map(|node| NodeConnection { node: node.clone(), output: 0 })]
+        };
+        (node, computes)
+    }
+
+    /// Renders frames of the graph and returns how many times each node was computed.
+    fn render(nodes: HashMap<NodeID, NodeData>, output: &NodeID, frames: usize, pixels: u32, counters: &[Arc<AtomicUsize>]) -> Vec<usize> {
+        let output = NodeConnection { node: output.clone(), output: 0 };
+        let mut graph = CompiledGraph::compile(&nodes, &[output], pixels, false).unwrap();
+        let mut render_info = crate::RenderInfo::new(&crate::spatial_map());
+        let mut context = NodeContext {
+            render_context: RenderContext { delta: std::time::Duration::ZERO, time: 0., pixels },
+            render_info: &mut render_info,
+            pixel: 0,
+            per_pixel: false,
+            inputs_vary: false,
+            group_inputs: &[]
+        };
+        for _ in 0..frames {
+            graph.run_frame(&mut context).unwrap();
+            for pixel in 0..pixels {
+                context.pixel = pixel;
+                graph.run_pixel(&mut context).unwrap();
+            }
+        }
+        counters.iter().map(|counter| counter.load(Ordering::Relaxed)).collect()
+    }
+
+    #[test]
+    fn frame_nodes_run_once_per_frame() {
+        let (frame_id, pixel_id, downstream_id) = (NodeID::new(), NodeID::new(), NodeID::new());
+        let (frame_node, frame_computes) = counting_node(false, None);
+        let (pixel_node, pixel_computes) = counting_node(true, Some(&frame_id));
+        // Nodes that don't vary themselves still run for every pixel if an input varies
+        let (downstream_node, downstream_computes) = counting_node(false, Some(&pixel_id));
+        let nodes = HashMap::from([(frame_id, frame_node), (pixel_id, pixel_node), (downstream_id.clone(), downstream_node)]);
+
+        let computes = render(nodes, &downstream_id, 3, 10, &[frame_computes, pixel_computes, downstream_computes]);
+        assert_eq!(computes, vec![3, 30, 30]);
+    }
+
+    #[test]
+    fn frame_only_graphs_run_once_per_frame() {
+        let (first_id, second_id) = (NodeID::new(), NodeID::new());
+        let (first_node, first_computes) = counting_node(false, None);
+        let (second_node, second_computes) = counting_node(false, Some(&first_id));
+        let nodes = HashMap::from([(first_id, first_node), (second_id.clone(), second_node)]);
+
+        let computes = render(nodes, &second_id, 4, 10, &[first_computes, second_computes]);
+        assert_eq!(computes, vec![4, 4]);
+    }
+
+    #[test]
+    fn rejects_cycles() {
+        let (first_id, second_id) = (NodeID::new(), NodeID::new());
+        let (first_node, _) = counting_node(false, Some(&second_id));
+        let (second_node, _) = counting_node(false, Some(&first_id));
+        let nodes = HashMap::from([(first_id.clone(), first_node), (second_id, second_node)]);
+
+        let output = NodeConnection { node: first_id, output: 0 };
+        let error = CompiledGraph::compile(&nodes, &[output], 10, false).unwrap_err();
+        assert_eq!(error, "The node graph contains a cycle");
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::register_node;
+
+use super::{compiler::{CachedGraph, CompiledGraph}, node::{Node, NodeConnection, NodeContext, NodeID, NodeParameter, PortInfo}, types::{AnyType, TypeInfo}, NodeData};
+
+/// A reusable subgraph that is used like a single node.
+/// Groups are saved in a library alongside the effect presets, and every group node holds its own copy of the group.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct NodeGroup {
+    /// The inputs exposed by the group. Group input nodes inside the group read these by index.
+    inputs: Vec<PortInfo>,
+    /// The outputs exposed by the group.
+    outputs: Vec<GroupOutput>,
+    nodes: HashMap<NodeID, NodeData>
+}
+
+/// An output exposed by a node group.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct GroupOutput {
+    name: String,
+    type_info: TypeInfo,
+    /// The node output inside the group that provides the value. Unconnected outputs use a default value.
+    source: Option<NodeConnection>
+}
+
+impl NodeGroup {
+    /// Makes sure every exposed output is connected to a node output of a compatible type.
+    fn validate(&self) -> Result<(), String> {
+        for output in &self.outputs {
+            let Some(source) = &output.source else {
+                continue;
+            };
+            let node = self.nodes.get(&source.node)
+                .ok_or_else(|| format!("The group output {} is connected to a node that doesn't exist", output.name))?;
+            let port = node.instance.output_ports()
+                .get(source.output as usize)
+                .ok_or_else(|| format!("The group output {} is connected to an output {} doesn't have", output.name, node.instance.name()))?;
+            if !port.type_info.can_coerce_to(&output.type_info) {
+                return Err(format!("The group output {} expects a {:?} value, but is connected to a {:?} output", output.name, output.type_info, port.type_info));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Evaluates a node group, set with the "group" parameter.
+#[derive(Clone)]
+struct GroupNode {
+    group: NodeGroup,
+    outputs: Vec<PortInfo>,
+    /// The group's graph, compiled the first time the node is computed.
+    compiled: Option<CachedGraph>,
+    /// The frame the compiled graph last computed its per-frame nodes for.
+    computed_frame: Option<usize>
+}
+
+impl GroupNode {
+    fn compile(&self, pixels: u32, inputs_vary: bool) -> Result<CompiledGraph, String> {
+        let sources = self.group.outputs.iter()
+            .filter_map(|output| output.source.clone())
+            .collect::<Vec<_>>();
+        CompiledGraph::compile(&self.group.nodes, &sources, pixels, inputs_vary)
+    }
+}
+
+impl Node for GroupNode {
+    fn name(&self) -> &'static str {
+        "Group"
+    }
+
+    fn input_ports(&self) -> &[PortInfo] {
+        &self.group.inputs
+    }
+
+    fn output_ports(&self) -> &[PortInfo] {
+        &self.outputs
+    }
+
+    fn compute(&mut self, inputs: &[AnyType], outputs: &mut [AnyType], context: &mut NodeContext) -> Result<(), String> {
+        // Nodes inside the group that depend on its inputs only need to be computed for every pixel if the inputs vary
+        let pixels = context.render_context.pixels;
+        let inputs_vary = context.inputs_vary;
+        if self.compiled.as_ref().is_none_or(|cached| !cached.is_for(pixels, inputs_vary)) {
+            self.compiled = Some(CachedGraph::new(pixels, inputs_vary, self.compile(pixels, inputs_vary)));
+            self.computed_frame = None;
+        }
+        let graph = self.compiled.as_mut().unwrap().graph()?;
+
+        let mut group_context = NodeContext {
+            render_context: context.render_context,
+            render_info: context.render_info,
+            pixel: context.pixel,
+            per_pixel: context.per_pixel,
+            inputs_vary: false,
+            group_inputs: inputs
+        };
+
+        // If the group's inputs vary per pixel, this is called for every pixel, but nodes inside the
+        // group that don't depend on its inputs or anything else varying are still only computed once per frame.
+        let frame = group_context.render_info.frames;
+        if self.computed_frame != Some(frame) {
+            graph.run_frame(&mut group_context)?;
+            self.computed_frame = Some(frame);
+        }
+        graph.run_pixel(&mut group_context)?;
+
+        let mut connected_outputs = 0;
+        for (output, value) in self.group.outputs.iter().zip(outputs.iter_mut()) {
+            *value = match output.source {
+                Some(_) => {
+                    connected_outputs += 1;
+                    graph.output(connected_outputs - 1).clone()
+                }
+                None => AnyType::default_for(&output.type_info, pixels)
+            };
+        }
+        Ok(())
+    }
+
+    fn varies_per_pixel(&self) -> bool {
+        // Group inputs only vary if the inputs of this node do, which the compiler already accounts for
+        self.group.nodes.values().any(|node| node.instance.varies_per_pixel())
+    }
+
+    fn parameters(&self) -> HashMap<String, NodeParameter> {
+        HashMap::from([("group".to_string(), NodeParameter::Group(Box::new(self.group.clone())))])
+    }
+
+    fn set_parameter(&mut self, name: &str, value: NodeParameter) -> Result<(), String> {
+        match (name, value) {
+            ("group", NodeParameter::Group(group)) => {
+                group.validate()?;
+                self.outputs = group.outputs.iter().map(|output| PortInfo {
+                    name: output.name.clone(),
+                    type_info: output.type_info.clone()
+                }).collect();
+                self.group = *group;
+                self.compiled = None;
+                Ok(())
+            }
+            ("group", _) => Err("The group parameter must be a node group".to_string()),
+            _ => Err(format!("Group has no parameter named {}", name))
+        }
+    }
+}
+
+register_node!("GroupNode", GroupNode {
+    group: NodeGroup::default(),
+    outputs: vec![],
+    compiled: None,
+    computed_frame: None
+});
+
+/// Outputs one of the inputs of the group it's in, chosen with the "index" parameter.
+#[derive(Clone)]
+struct GroupInputNode {
+    index: i32,
+    outputs: Vec<PortInfo>
+}
+
+impl Node for GroupInputNode {
+    fn name(&self) -> &'static str {
+        "Group Input"
+    }
+
+    fn input_ports(&self) -> &[PortInfo] {
+        &[]
+    }
+
+    fn output_ports(&self) -> &[PortInfo] {
+        &self.outputs
+    }
+
+    fn compute(&mut self, _inputs: &[AnyType], outputs: &mut [AnyType], context: &mut NodeContext) -> Result<(), String> {
+        let value = usize::try_from(self.index).ok()
+            .and_then(|index| context.group_inputs.get(index))
+            .ok_or_else(|| format!("The group has no input {}", self.index))?;
+        outputs[0] = value.clone().coerce_to(&self.outputs[0].type_info)?;
+        Ok(())
+    }
+
+    fn parameters(&self) -> HashMap<String, NodeParameter> {
+        HashMap::from([
+            ("index".to_string(), NodeParameter::Int(self.index)),
+            ("type".to_string(), NodeParameter::Type(self.outputs[0].type_info.clone()))
+        ])
+    }
+
+    fn set_parameter(&mut self, name: &str, value: NodeParameter) -> Result<(), String> {
+        match (name, value) {
+            ("index", NodeParameter::Int(index)) => self.index = index,
+            ("type", NodeParameter::Type(type_info)) => self.outputs[0].type_info = type_info,
+            ("index" | "type", _) => return Err(format!("Invalid value for the {} parameter", name)),
+            _ => return Err(format!("Group Input has no parameter named {}", name))
+        }
+        Ok(())
+    }
+}
+
+register_node!("GroupInputNode", GroupInputNode {
+    index: 0,
+    outputs: vec![PortInfo {
+        name: "value".into(),
+        type_info: TypeInfo::Float,
+    }]
+});
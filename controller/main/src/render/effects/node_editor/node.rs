@@ -1,10 +1,12 @@
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 use dyn_clone::DynClone;
 use reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
-use super::types::{AnyType, TryConvert, TryConvertBack, TypeInfo};
+use crate::{render::{effects::{AnyEffect, RenderContext}, frame::PixelColor}, RenderInfo};
+
+use super::{group::NodeGroup, types::{AnyType, TryConvert, TryConvertBack, TypeInfo}};
 
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
@@ -33,11 +35,70 @@ pub struct PortInfo {
     pub type_info: TypeInfo
 }
 
+/// A connection from an output port of another node.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct NodeConnection {
+    /// The node the value comes from.
+    pub node: NodeID,
+    /// The index of the output port on that node.
+    pub output: u32
+}
+
+/// A value configured in the editor rather than connected from another node.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", content = "value")]
+pub enum NodeParameter {
+    Float(f64),
+    Int(i32),
+    Bool(bool),
+    String(String),
+    Color(PixelColor),
+    Effect(Box<AnyEffect>),
+    Type(TypeInfo),
+    Group(Box<NodeGroup>)
+}
+
+/// The state available to nodes while the graph is evaluated.
+pub struct NodeContext<'a> {
+    pub render_context: RenderContext,
+    pub render_info: &'a mut RenderInfo,
+    /// The pixel currently being evaluated.
+    pub pixel: u32,
+    /// Whether the node being computed is evaluated separately for every pixel, rather than once per frame.
+    pub per_pixel: bool,
+    /// Whether any of the inputs of the node being computed vary per pixel.
+    pub inputs_vary: bool,
+    /// The values passed to the inputs of the group being evaluated. Empty outside of groups.
+    pub group_inputs: &'a [AnyType]
+}
+
 pub trait Node : DynClone + Send + Sync {
     fn name(&self) -> &'static str;
     fn input_ports(&self) -> &[PortInfo];
     fn output_ports(&self) -> &[PortInfo];
-    fn compute(&mut self, inputs: VecDeque<AnyType>) -> Result<Vec<AnyType>, String>;
+    /// Computes the node's outputs from its inputs.
+    /// `outputs` has one slot for every output port, which must all be written.
+    fn compute(&mut self, inputs: &[AnyType], outputs: &mut [AnyType], context: &mut NodeContext) -> Result<(), String>;
+
+    /// Whether this node's output can differ between pixels of the same frame, even if its inputs don't.
+    /// Nodes that don't vary per pixel (and don't depend on nodes that do) are only computed once per frame.
+    fn varies_per_pixel(&self) -> bool {
+        false
+    }
+
+    /// Gets the current values of this node's parameters.
+    fn parameters(&self) -> HashMap<String, NodeParameter> {
+        HashMap::new()
+    }
+
+    /// Sets one of this node's parameters.
+    fn set_parameter(&mut self, name: &str, _value: NodeParameter) -> Result<(), String> {
+        Err(format!("{} has no parameter named {}", self.name(), name))
+    }
+
+    /// Prepares the node to be computed, like optimizing effects it renders.
+    /// Called on the copies of nodes in compiled graphs, so the saved graph keeps its original form.
+    fn optimize(&mut self) {}
 }
 
 /// A simple node with no state or parameters.
@@ -52,7 +113,7 @@ pub struct SimpleTypedNode<I, O> {
 
 impl<I, O> SimpleTypedNode<I, O>
 where
-    VecDeque<AnyType>: TryConvert<I> + 'static,
+    [AnyType]: TryConvert<I> + 'static,
     O: TryConvertBack + 'static,
 {
     pub fn new(
@@ -72,7 +133,7 @@ where
 
 impl<I, O> Node for SimpleTypedNode<I, O>
 where
-    VecDeque<AnyType>: TryConvert<I> + 'static,
+    [AnyType]: TryConvert<I> + 'static,
     I: Clone,
     O: Clone + TryConvertBack + 'static,
 {
@@ -88,9 +149,79 @@ where
         &self.outputs
     }
 
-    fn compute(&mut self, inputs: VecDeque<AnyType>) -> Result<Vec<AnyType>, String> {
+    fn compute(&mut self, inputs: &[AnyType], outputs: &mut [AnyType], _context: &mut NodeContext) -> Result<(), String> {
         let typed_inputs: I = inputs.try_convert()?;
         let output = (self.func)(typed_inputs)?;
-        Ok(output.try_convert_back())
+        output.try_convert_back(outputs)
+    }
+}
+
+type StatefulNodeFunction<S, I, O> = dyn Fn(&mut S, I, &NodeContext) -> Result<O, String> + Send + Sync;
+
+/// A node with per-instance state, like an oscillator or a smoothing filter.
+/// The state lives in the node itself, so cloning the node (or the effect that contains it) clones its state too.
+/// When the node is evaluated for every pixel, each pixel gets its own copy of the state.
+#[derive(Clone)]
+pub struct StatefulTypedNode<S, I, O> {
+    name: &'static str,
+    inputs: Vec<PortInfo>,
+    outputs: Vec<PortInfo>,
+    initial_state: S,
+    states: Vec<S>,
+    func: Arc<Box<StatefulNodeFunction<S, I, O>>>,
+}
+
+impl<S, I, O> StatefulTypedNode<S, I, O>
+where
+    S: Clone,
+    [AnyType]: TryConvert<I> + 'static,
+    O: TryConvertBack + 'static,
+{
+    pub fn new(
+        name: &'static str,
+        inputs: Vec<PortInfo>,
+        outputs: Vec<PortInfo>,
+        initial_state: S,
+        func: impl Fn(&mut S, I, &NodeContext) -> Result<O, String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            inputs,
+            outputs,
+            initial_state,
+            states: vec![],
+            func: Arc::new(Box::new(func)),
+        }
+    }
+}
+
+impl<S, I, O> Node for StatefulTypedNode<S, I, O>
+where
+    S: Clone + Send + Sync + 'static,
+    [AnyType]: TryConvert<I> + 'static,
+    I: Clone,
+    O: Clone + TryConvertBack + 'static,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn input_ports(&self) -> &[PortInfo] {
+        &self.inputs
+    }
+
+    fn output_ports(&self) -> &[PortInfo] {
+        &self.outputs
+    }
+
+    fn compute(&mut self, inputs: &[AnyType], outputs: &mut [AnyType], context: &mut NodeContext) -> Result<(), String> {
+        let index = if context.per_pixel { context.pixel as usize } else { 0 };
+        if self.states.len() <= index {
+            self.states.resize(index + 1, self.initial_state.clone());
+        }
+
+        let typed_inputs: I = inputs.try_convert()?;
+        let output = (self.func)(&mut self.states[index], typed_inputs, context)?;
+        output.try_convert_back(outputs)
     }
 }
\ No newline at end of file
@@ -702,4 +702,766 @@ mod color {
             a.0.lerp(&b.0, t.0),
         ),)),
     ));
-}
\ No newline at end of file
+}
+mod inputs {
+    use std::collections::HashMap;
+
+    use crate::{register_node, render::{effects::node_editor::{node::{Node, NodeContext, NodeParameter, PortInfo}, types::{AnyType, BoolValue, ColorValue, FloatValue, IntegerValue, StringValue}, TypeInfo}, frame::PixelColor}};
+
+    /// Outputs the current render time in seconds.
+    #[derive(Clone)]
+    struct TimeNode {
+        outputs: Vec<PortInfo>
+    }
+
+    impl Node for TimeNode {
+        fn name(&self) -> &'static str {
+            "Time"
+        }
+
+        fn input_ports(&self) -> &[PortInfo] {
+            &[]
+        }
+
+        fn output_ports(&self) -> &[PortInfo] {
+            &self.outputs
+        }
+
+        fn compute(&mut self, _inputs: &[AnyType], outputs: &mut [AnyType], context: &mut NodeContext) -> Result<(), String> {
+            outputs[0] = AnyType::FloatValue(FloatValue(context.render_context.time));
+            Ok(())
+        }
+    }
+
+    register_node!("TimeNode", TimeNode {
+        outputs: vec![PortInfo {
+            name: "time".into(),
+            type_info: TypeInfo::Float,
+        }],
+    });
+
+    /// Outputs the index of the pixel being evaluated, and its position along the strip from 0 to 1.
+    #[derive(Clone)]
+    struct PixelNode {
+        outputs: Vec<PortInfo>
+    }
+
+    impl Node for PixelNode {
+        fn name(&self) -> &'static str {
+            "Pixel"
+        }
+
+        fn input_ports(&self) -> &[PortInfo] {
+            &[]
+        }
+
+        fn output_ports(&self) -> &[PortInfo] {
+            &self.outputs
+        }
+
+        fn compute(&mut self, _inputs: &[AnyType], outputs: &mut [AnyType], context: &mut NodeContext) -> Result<(), String> {
+            outputs[0] = AnyType::IntegerValue(IntegerValue(context.pixel as i32));
+            outputs[1] = AnyType::FloatValue(FloatValue(context.pixel as f64 / context.render_context.pixels as f64));
+            Ok(())
+        }
+
+        fn varies_per_pixel(&self) -> bool {
+            true
+        }
+    }
+
+    register_node!("PixelNode", PixelNode {
+        outputs: vec![
+            PortInfo {
+                name: "index".into(),
+                type_info: TypeInfo::Int,
+            },
+            PortInfo {
+                name: "position".into(),
+                type_info: TypeInfo::Float,
+            },
+        ],
+    });
+
+    /// Outputs a constant value set with the "value" parameter.
+    #[derive(Clone)]
+    struct ConstantNode {
+        name: &'static str,
+        outputs: Vec<PortInfo>,
+        value: NodeParameter
+    }
+
+    impl ConstantNode {
+        fn new(name: &'static str, type_info: TypeInfo, value: NodeParameter) -> Self {
+            Self {
+                name,
+                outputs: vec![PortInfo {
+                    name: "value".into(),
+                    type_info,
+                }],
+                value
+            }
+        }
+    }
+
+    impl Node for ConstantNode {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn input_ports(&self) -> &[PortInfo] {
+            &[]
+        }
+
+        fn output_ports(&self) -> &[PortInfo] {
+            &self.outputs
+        }
+
+        fn compute(&mut self, _inputs: &[AnyType], outputs: &mut [AnyType], _context: &mut NodeContext) -> Result<(), String> {
+            outputs[0] = match &self.value {
+                NodeParameter::Float(value) => AnyType::FloatValue(FloatValue(*value)),
+                NodeParameter::Int(value) => AnyType::IntegerValue(IntegerValue(*value)),
+                NodeParameter::Bool(value) => AnyType::BoolValue(BoolValue(*value)),
+                NodeParameter::String(value) => AnyType::StringValue(StringValue(value.clone())),
+                NodeParameter::Color(value) => AnyType::ColorValue(ColorValue(value.clone())),
+                NodeParameter::Effect(_) => return Err("Constants can't hold effects".to_string()),
+                NodeParameter::Type(_) | NodeParameter::Group(_) => return Err("Constants can't hold types or groups".to_string()),
+            };
+            Ok(())
+        }
+
+        fn parameters(&self) -> HashMap<String, NodeParameter> {
+            HashMap::from([("value".to_string(), self.value.clone())])
+        }
+
+        fn set_parameter(&mut self, name: &str, value: NodeParameter) -> Result<(), String> {
+            if name != "value" {
+                return Err(format!("{} has no parameter named {}", self.name, name));
+            }
+            if std::mem::discriminant(&value) != std::mem::discriminant(&self.value) {
+                return Err(format!("{} can't hold this type of value", self.name));
+            }
+            self.value = value;
+            Ok(())
+        }
+    }
+
+    register_node!("FloatNode", ConstantNode::new("Float", TypeInfo::Float, NodeParameter::Float(0.)));
+    register_node!("IntNode", ConstantNode::new("Int", TypeInfo::Int, NodeParameter::Int(0)));
+    register_node!("BoolNode", ConstantNode::new("Bool", TypeInfo::Bool, NodeParameter::Bool(false)));
+    register_node!("StringNode", ConstantNode::new("String", TypeInfo::String, NodeParameter::String(String::new())));
+    register_node!("ColorNode", ConstantNode::new("Color", TypeInfo::Color, NodeParameter::Color(PixelColor::BLACK)));
+}
+
+mod frames {
+    use std::collections::HashMap;
+
+    use crate::{register_node, render::{effects::{node_editor::{node::{Node, NodeContext, NodeParameter, PortInfo, SimpleTypedNode}, types::{AnyType, ColorValue, FloatValue, FrameValue, IntegerValue}, TypeInfo}, AnyEffect, Effect, SolidColorEffect}, frame::{Frame, PixelColor}}};
+
+    /// Renders an effect and outputs it as a frame, so node graphs can build on top of existing effects.
+    #[derive(Clone)]
+    struct EffectNode {
+        outputs: Vec<PortInfo>,
+        effect: Box<AnyEffect>
+    }
+
+    impl Node for EffectNode {
+        fn name(&self) -> &'static str {
+            "Effect"
+        }
+
+        fn input_ports(&self) -> &[PortInfo] {
+            &[]
+        }
+
+        fn output_ports(&self) -> &[PortInfo] {
+            &self.outputs
+        }
+
+        fn compute(&mut self, _inputs: &[AnyType], outputs: &mut [AnyType], context: &mut NodeContext) -> Result<(), String> {
+            let frame = self.effect.render(context.render_context, context.render_info);
+            outputs[0] = AnyType::FrameValue(frame.into());
+            Ok(())
+        }
+
+        fn parameters(&self) -> HashMap<String, NodeParameter> {
+            HashMap::from([("effect".to_string(), NodeParameter::Effect(self.effect.clone()))])
+        }
+
+        fn set_parameter(&mut self, name: &str, value: NodeParameter) -> Result<(), String> {
+            match (name, value) {
+                ("effect", NodeParameter::Effect(effect)) => {
+                    self.effect = effect;
+                    Ok(())
+                }
+                ("effect", _) => Err("The effect parameter must be an effect".to_string()),
+                _ => Err(format!("Effect has no parameter named {}", name))
+            }
+        }
+
+        fn optimize(&mut self) {
+            self.effect.optimize();
+        }
+    }
+
+    register_node!("EffectNode", EffectNode {
+        outputs: vec![PortInfo {
+            name: "frame".into(),
+            type_info: TypeInfo::Frame,
+        }],
+        effect: Box::new(SolidColorEffect::new(PixelColor::BLACK, 0, 0)),
+    });
+
+    register_node!("SampleFrameNode", SimpleTypedNode::new(
+        "SampleFrame",
+        vec![
+            PortInfo {
+                name: "frame".into(),
+                type_info: TypeInfo::Frame,
+            },
+            PortInfo {
+                name: "index".into(),
+                type_info: TypeInfo::Int,
+            },
+        ],
+        vec![PortInfo {
+            name: "color".into(),
+            type_info: TypeInfo::Color,
+        }],
+        |(frame, index): (FrameValue, IntegerValue)| {
+            let pixels = frame.0.pixel_count().max(1) as i64;
+            let index = (index.0 as i64).rem_euclid(pixels) as u32;
+            Ok((ColorValue(frame.0.get_pixel(index)),))
+        },
+    ));
+
+    /// Combines two frames pixel by pixel.
+    fn combine_frames(a: &Frame, b: &Frame, combine: impl Fn(&PixelColor, &PixelColor) -> PixelColor) -> Frame {
+        let mut frame = Frame::empty(a.pixel_count().max(b.pixel_count()));
+        for i in 0..frame.pixel_count() {
+            frame.set_pixel(i, combine(&a.get_pixel(i), &b.get_pixel(i)));
+        }
+        frame
+    }
+
+    register_node!("BlendFramesNode", SimpleTypedNode::new(
+        "BlendFrames",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::Frame,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::Frame,
+            },
+            PortInfo {
+                name: "t".into(),
+                type_info: TypeInfo::Float,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Frame,
+        }],
+        |(a, b, t): (FrameValue, FrameValue, FloatValue)| Ok((FrameValue::from(
+            combine_frames(&a.0, &b.0, |a, b| a.lerp(b, t.0.clamp(0., 1.)))
+        ),)),
+    ));
+
+    register_node!("AlphaCompositeFramesNode", SimpleTypedNode::new(
+        "AlphaCompositeFrames",
+        vec![
+            PortInfo {
+                name: "bottom".into(),
+                type_info: TypeInfo::Frame,
+            },
+            PortInfo {
+                name: "top".into(),
+                type_info: TypeInfo::Frame,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Frame,
+        }],
+        |(bottom, top): (FrameValue, FrameValue)| Ok((FrameValue::from(
+            combine_frames(&bottom.0, &top.0, |bottom, top| {
+                let mut pixel = bottom.lerp(top, top.alpha);
+                pixel.alpha = bottom.alpha.max(top.alpha);
+                pixel
+            })
+        ),)),
+    ));
+
+    register_node!("AddFramesNode", SimpleTypedNode::new(
+        "AddFrames",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::Frame,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::Frame,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Frame,
+        }],
+        |(a, b): (FrameValue, FrameValue)| Ok((FrameValue::from(
+            combine_frames(&a.0, &b.0, |a, b| PixelColor::new(
+                a.r.saturating_add(b.r),
+                a.g.saturating_add(b.g),
+                a.b.saturating_add(b.b),
+                a.alpha.max(b.alpha)
+            ))
+        ),)),
+    ));
+
+    register_node!("MaskFrameNode", SimpleTypedNode::new(
+        "MaskFrame",
+        vec![
+            PortInfo {
+                name: "frame".into(),
+                type_info: TypeInfo::Frame,
+            },
+            PortInfo {
+                name: "mask".into(),
+                type_info: TypeInfo::Frame,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Frame,
+        }],
+        // The mask's brightness (scaled by its alpha) is multiplied into the frame's alpha
+        |(frame, mask): (FrameValue, FrameValue)| Ok((FrameValue::from(
+            combine_frames(&frame.0, &mask.0, |pixel, mask| {
+                let brightness = mask.r.max(mask.g).max(mask.b) as f64 / 255.;
+                pixel.with_alpha(pixel.alpha * brightness * mask.alpha)
+            })
+        ),)),
+    ));
+}
+
+mod stateful {
+    use std::collections::VecDeque;
+
+    use crate::{register_node, render::{effects::node_editor::{types::{BoolValue, FloatValue}, TypeInfo}, random::{value_noise_1d, SeededRng}}};
+    use super::super::node::{NodeContext, PortInfo, SimpleTypedNode, StatefulTypedNode};
+
+    /// The longest delay supported by the delay node, in seconds.
+    static MAX_DELAY: f64 = 10.;
+
+    /// Creates a low-frequency oscillator that outputs values between 0 and 1.
+    /// The phase is accumulated every frame, so changing the frequency doesn't cause jumps.
+    fn lfo(name: &'static str, shape: fn(f64) -> f64) -> StatefulTypedNode<f64, (FloatValue,), (FloatValue,)> {
+        StatefulTypedNode::new(
+            name,
+            vec![PortInfo {
+                name: "frequency".into(),
+                type_info: TypeInfo::Float,
+            }],
+            vec![PortInfo {
+                name: "value".into(),
+                type_info: TypeInfo::Float,
+            }],
+            0.,
+            move |phase: &mut f64, (frequency,): (FloatValue,), context: &NodeContext| {
+                *phase = (*phase + frequency.0 * context.render_context.delta.as_secs_f64()).rem_euclid(1.);
+                Ok((FloatValue(shape(*phase)),))
+            },
+        )
+    }
+
+    register_node!("SineLFONode", lfo("SineLFO", |phase| (phase * std::f64::consts::TAU).sin() * 0.5 + 0.5));
+    register_node!("TriangleLFONode", lfo("TriangleLFO", |phase| 1. - (phase * 2. - 1.).abs()));
+    register_node!("SquareLFONode", lfo("SquareLFO", |phase| if phase < 0.5 { 1. } else { 0. }));
+    register_node!("SawLFONode", lfo("SawLFO", |phase| phase));
+
+    register_node!("SmoothNode", StatefulTypedNode::new(
+        "Smooth",
+        vec![
+            PortInfo {
+                name: "value".into(),
+                type_info: TypeInfo::Float,
+            },
+            PortInfo {
+                name: "time_constant".into(),
+                type_info: TypeInfo::Float,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Float,
+        }],
+        None,
+        |smoothed: &mut Option<f64>, (value, time_constant): (FloatValue, FloatValue), context: &NodeContext| {
+            // Exponential smoothing that is independent of the frame rate
+            let current = smoothed.unwrap_or(value.0);
+            let factor = if time_constant.0 <= 0. {
+                1.
+            } else {
+                1. - (-context.render_context.delta.as_secs_f64() / time_constant.0).exp()
+            };
+            let next = current + (value.0 - current) * factor;
+            *smoothed = Some(next);
+            Ok((FloatValue(next),))
+        },
+    ));
+
+    register_node!("SampleAndHoldNode", StatefulTypedNode::new(
+        "SampleAndHold",
+        vec![
+            PortInfo {
+                name: "value".into(),
+                type_info: TypeInfo::Float,
+            },
+            PortInfo {
+                name: "trigger".into(),
+                type_info: TypeInfo::Bool,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Float,
+        }],
+        (None, false),
+        |(held, last_trigger): &mut (Option<f64>, bool), (value, trigger): (FloatValue, BoolValue), _context: &NodeContext| {
+            // Sample on the rising edge of the trigger, or the first time we're computed
+            if held.is_none() || (trigger.0 && !*last_trigger) {
+                *held = Some(value.0);
+            }
+            *last_trigger = trigger.0;
+            Ok((FloatValue(held.unwrap()),))
+        },
+    ));
+
+    register_node!("DelayNode", StatefulTypedNode::new(
+        "Delay",
+        vec![
+            PortInfo {
+                name: "value".into(),
+                type_info: TypeInfo::Float,
+            },
+            PortInfo {
+                name: "delay".into(),
+                type_info: TypeInfo::Float,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Float,
+        }],
+        VecDeque::new(),
+        |history: &mut VecDeque<(f64, f64)>, (value, delay): (FloatValue, FloatValue), context: &NodeContext| {
+            let time = context.render_context.time;
+            history.push_back((time, value.0));
+
+            // Only the newest sample that is at least `delay` seconds old is needed, so older ones are dropped
+            let target_time = time - delay.0.clamp(0., MAX_DELAY);
+            while history.get(1).is_some_and(|(sample_time, _)| *sample_time <= target_time) {
+                history.pop_front();
+            }
+
+            // Samples are in time order
+            let newer = history.partition_point(|(sample_time, _)| *sample_time <= target_time);
+            let delayed = history.get(newer.saturating_sub(1))
+                .map(|(_, value)| *value)
+                .unwrap_or(value.0);
+            Ok((FloatValue(delayed),))
+        },
+    ));
+
+    register_node!("RandomNode", StatefulTypedNode::new(
+        "Random",
+        vec![PortInfo {
+            name: "seed".into(),
+            type_info: TypeInfo::Float,
+        }],
+        vec![PortInfo {
+            name: "value".into(),
+            type_info: TypeInfo::Float,
+        }],
+        None,
+        |rng: &mut Option<(f64, SeededRng)>, (seed,): (FloatValue,), context: &NodeContext| {
+            // Restart the sequence if the seed changes. Each pixel gets a different sequence.
+            if rng.as_ref().is_none_or(|(current_seed, _)| *current_seed != seed.0) {
+                let pixel_seed = if context.per_pixel { context.pixel as u64 } else { 0 };
+                *rng = Some((seed.0, SeededRng::new(seed.0.to_bits() ^ pixel_seed.rotate_left(32))));
+            }
+            Ok((FloatValue(rng.as_mut().unwrap().1.next_f64()),))
+        },
+    ));
+
+    register_node!("NoiseNode", SimpleTypedNode::new(
+        "Noise",
+        vec![
+            PortInfo {
+                name: "x".into(),
+                type_info: TypeInfo::Float,
+            },
+            PortInfo {
+                name: "seed".into(),
+                type_info: TypeInfo::Float,
+            },
+        ],
+        vec![PortInfo {
+            name: "value".into(),
+            type_info: TypeInfo::Float,
+        }],
+        |(x, seed): (FloatValue, FloatValue)| Ok((FloatValue(value_noise_1d(seed.0.to_bits(), x.0)),)),
+    ));
+}
+
+mod conversions {
+    use crate::{register_node, render::effects::node_editor::{types::{BoolValue, ColorValue, FloatValue, IntegerValue, StringValue}, TypeInfo}};
+    use super::super::node::{PortInfo, SimpleTypedNode};
+
+    // Integers and floats are converted implicitly, but explicit conversions make the rounding mode clear
+
+    register_node!("FloorToIntNode", SimpleTypedNode::new(
+        "FloorToInt",
+        vec![PortInfo {
+            name: "value".into(),
+            type_info: TypeInfo::Float,
+        }],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Int,
+        }],
+        |(value,): (FloatValue,)| Ok((IntegerValue(value.0.floor() as i32),)),
+    ));
+
+    register_node!("RoundToIntNode", SimpleTypedNode::new(
+        "RoundToInt",
+        vec![PortInfo {
+            name: "value".into(),
+            type_info: TypeInfo::Float,
+        }],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Int,
+        }],
+        |(value,): (FloatValue,)| Ok((IntegerValue(value.0.round() as i32),)),
+    ));
+
+    register_node!("ToFloatNode", SimpleTypedNode::new(
+        "ToFloat",
+        vec![PortInfo {
+            name: "value".into(),
+            type_info: TypeInfo::Int,
+        }],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Float,
+        }],
+        |(value,): (IntegerValue,)| Ok((FloatValue(value.0 as f64),)),
+    ));
+
+    register_node!("IntToStringNode", SimpleTypedNode::new(
+        "IntToString",
+        vec![PortInfo {
+            name: "value".into(),
+            type_info: TypeInfo::Int,
+        }],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::String,
+        }],
+        |(value,): (IntegerValue,)| Ok((StringValue(value.0.to_string()),)),
+    ));
+
+    register_node!("FloatToStringNode", SimpleTypedNode::new(
+        "FloatToString",
+        vec![PortInfo {
+            name: "value".into(),
+            type_info: TypeInfo::Float,
+        }],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::String,
+        }],
+        |(value,): (FloatValue,)| Ok((StringValue(value.0.to_string()),)),
+    ));
+
+    // Integer arithmetic, mostly useful for pixel indices
+
+    register_node!("IntAddNode", SimpleTypedNode::new(
+        "IntAdd",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::Int,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::Int,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Int,
+        }],
+        |(a, b): (IntegerValue, IntegerValue)| Ok((IntegerValue(a.0.wrapping_add(b.0)),)),
+    ));
+
+    register_node!("IntSubtractNode", SimpleTypedNode::new(
+        "IntSubtract",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::Int,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::Int,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Int,
+        }],
+        |(a, b): (IntegerValue, IntegerValue)| Ok((IntegerValue(a.0.wrapping_sub(b.0)),)),
+    ));
+
+    register_node!("IntMultiplyNode", SimpleTypedNode::new(
+        "IntMultiply",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::Int,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::Int,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Int,
+        }],
+        |(a, b): (IntegerValue, IntegerValue)| Ok((IntegerValue(a.0.wrapping_mul(b.0)),)),
+    ));
+
+    register_node!("IntDivideNode", SimpleTypedNode::new(
+        "IntDivide",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::Int,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::Int,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Int,
+        }],
+        |(a, b): (IntegerValue, IntegerValue)| {
+            if b.0 == 0 {
+                return Err("Division by zero".to_string());
+            }
+            Ok((IntegerValue(a.0.div_euclid(b.0)),))
+        },
+    ));
+
+    register_node!("IntModuloNode", SimpleTypedNode::new(
+        "IntModulo",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::Int,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::Int,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Int,
+        }],
+        |(a, b): (IntegerValue, IntegerValue)| {
+            if b.0 == 0 {
+                return Err("Division by zero".to_string());
+            }
+            Ok((IntegerValue(a.0.rem_euclid(b.0)),))
+        },
+    ));
+
+    // Strings and selection
+
+    register_node!("StringEqualNode", SimpleTypedNode::new(
+        "StringEqual",
+        vec![
+            PortInfo {
+                name: "a".into(),
+                type_info: TypeInfo::String,
+            },
+            PortInfo {
+                name: "b".into(),
+                type_info: TypeInfo::String,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Bool,
+        }],
+        |(a, b): (StringValue, StringValue)| Ok((BoolValue(a.0 == b.0),)),
+    ));
+
+    register_node!("SelectFloatNode", SimpleTypedNode::new(
+        "SelectFloat",
+        vec![
+            PortInfo {
+                name: "condition".into(),
+                type_info: TypeInfo::Bool,
+            },
+            PortInfo {
+                name: "if_true".into(),
+                type_info: TypeInfo::Float,
+            },
+            PortInfo {
+                name: "if_false".into(),
+                type_info: TypeInfo::Float,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Float,
+        }],
+        |(condition, if_true, if_false): (BoolValue, FloatValue, FloatValue)| {
+            Ok((if condition.0 { if_true } else { if_false },))
+        },
+    ));
+
+    register_node!("SelectColorNode", SimpleTypedNode::new(
+        "SelectColor",
+        vec![
+            PortInfo {
+                name: "condition".into(),
+                type_info: TypeInfo::Bool,
+            },
+            PortInfo {
+                name: "if_true".into(),
+                type_info: TypeInfo::Color,
+            },
+            PortInfo {
+                name: "if_false".into(),
+                type_info: TypeInfo::Color,
+            },
+        ],
+        vec![PortInfo {
+            name: "result".into(),
+            type_info: TypeInfo::Color,
+        }],
+        |(condition, if_true, if_false): (BoolValue, ColorValue, ColorValue)| {
+            Ok((if condition.0 { if_true } else { if_false },))
+        },
+    ));
+}
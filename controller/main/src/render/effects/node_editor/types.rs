@@ -2,9 +2,9 @@ use reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
 use crate::render::frame::{Frame, PixelColor};
-use std::{collections::VecDeque};
+use std::sync::Arc;
 
-#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(tag = "type")]
 pub enum TypeInfo {
     Int,
@@ -15,8 +15,19 @@ pub enum TypeInfo {
     Frame
 }
 
+impl TypeInfo {
+    /// Whether an output of this type can be connected to an input of the other type.
+    /// Integers and floats are implicitly converted to each other; floats are rounded down.
+    pub fn can_coerce_to(&self, other: &TypeInfo) -> bool {
+        self == other || matches!((self, other), (TypeInfo::Int, TypeInfo::Float) | (TypeInfo::Float, TypeInfo::Int))
+    }
+}
+
 pub trait Type {
     fn upcast(self) -> AnyType;
+    /// Converts a value to this type, applying implicit coercions.
+    /// Returns the original value if it can't be converted.
+    fn coerce(value: AnyType) -> Result<Self, AnyType> where Self: Sized;
 }
 #[derive(Clone, Debug, Copy)]
 pub struct FloatValue(pub f64);
@@ -26,13 +37,23 @@ pub struct IntegerValue(pub i32);
 pub struct BoolValue(pub bool);
 #[derive(Clone, Debug)]
 pub struct ColorValue(pub PixelColor);
+/// Frames are shared rather than copied, since they're passed to nodes for every pixel.
 #[derive(Clone, Debug)]
-pub struct FrameValue(pub Frame);
+pub struct FrameValue(pub Arc<Frame>);
+#[derive(Clone, Debug)]
+pub struct StringValue(pub String);
 
 impl Type for FloatValue {
     fn upcast(self) -> AnyType {
         AnyType::FloatValue(self)
     }
+    fn coerce(value: AnyType) -> Result<Self, AnyType> {
+        match value {
+            AnyType::FloatValue(v) => Ok(v),
+            AnyType::IntegerValue(v) => Ok(FloatValue(v.0 as f64)),
+            v => Err(v)
+        }
+    }
 }
 impl From<f64> for FloatValue {
     fn from(value: f64) -> Self {
@@ -43,6 +64,12 @@ impl Type for BoolValue {
     fn upcast(self) -> AnyType {
         AnyType::BoolValue(self)
     }
+    fn coerce(value: AnyType) -> Result<Self, AnyType> {
+        match value {
+            AnyType::BoolValue(v) => Ok(v),
+            v => Err(v)
+        }
+    }
 }
 impl From<bool> for BoolValue {
     fn from(value: bool) -> Self {
@@ -53,6 +80,12 @@ impl Type for ColorValue {
     fn upcast(self) -> AnyType {
         AnyType::ColorValue(self)
     }
+    fn coerce(value: AnyType) -> Result<Self, AnyType> {
+        match value {
+            AnyType::ColorValue(v) => Ok(v),
+            v => Err(v)
+        }
+    }
 }
 impl From<PixelColor> for ColorValue {
     fn from(value: PixelColor) -> Self {
@@ -63,22 +96,51 @@ impl Type for FrameValue {
     fn upcast(self) -> AnyType {
         AnyType::FrameValue(self)
     }
+    fn coerce(value: AnyType) -> Result<Self, AnyType> {
+        match value {
+            AnyType::FrameValue(v) => Ok(v),
+            v => Err(v)
+        }
+    }
 }
 impl From<Frame> for FrameValue {
     fn from(value: Frame) -> Self {
-        return Self(value);
+        return Self(Arc::new(value));
     }
 }
 impl Type for IntegerValue {
     fn upcast(self) -> AnyType {
         AnyType::IntegerValue(self)
     }
+    fn coerce(value: AnyType) -> Result<Self, AnyType> {
+        match value {
+            AnyType::IntegerValue(v) => Ok(v),
+            AnyType::FloatValue(v) => Ok(IntegerValue(v.0.floor() as i32)),
+            v => Err(v)
+        }
+    }
 }
 impl From<i32> for IntegerValue {
     fn from(value: i32) -> Self {
         return Self(value);
     }
 }
+impl Type for StringValue {
+    fn upcast(self) -> AnyType {
+        AnyType::StringValue(self)
+    }
+    fn coerce(value: AnyType) -> Result<Self, AnyType> {
+        match value {
+            AnyType::StringValue(v) => Ok(v),
+            v => Err(v)
+        }
+    }
+}
+impl From<String> for StringValue {
+    fn from(value: String) -> Self {
+        return Self(value);
+    }
+}
 
 #[derive(Clone, Debug)]
 pub enum AnyType {
@@ -86,63 +148,92 @@ pub enum AnyType {
     BoolValue(BoolValue),
     ColorValue(ColorValue),
     FrameValue(FrameValue),
-    IntegerValue(IntegerValue)
+    IntegerValue(IntegerValue),
+    StringValue(StringValue)
 }
 
 impl AnyType {
+    /// The value used for an input port that isn't connected to anything.
+    pub fn default_for(type_info: &TypeInfo, pixels: u32) -> AnyType {
+        match type_info {
+            TypeInfo::Float => AnyType::FloatValue(FloatValue(0.)),
+            TypeInfo::Int => AnyType::IntegerValue(IntegerValue(0)),
+            TypeInfo::Bool => AnyType::BoolValue(BoolValue(false)),
+            TypeInfo::Color => AnyType::ColorValue(ColorValue(PixelColor::new(0, 0, 0, 0.))),
+            TypeInfo::Frame => AnyType::FrameValue(Frame::empty(pixels).into()),
+            TypeInfo::String => AnyType::StringValue(StringValue(String::new()))
+        }
+    }
+
+    /// Converts a value to the given type, applying implicit coercions.
+    pub fn coerce_to(self, type_info: &TypeInfo) -> Result<AnyType, String> {
+        let result = match type_info {
+            TypeInfo::Float => FloatValue::coerce(self).map(Type::upcast),
+            TypeInfo::Int => IntegerValue::coerce(self).map(Type::upcast),
+            TypeInfo::Bool => BoolValue::coerce(self).map(Type::upcast),
+            TypeInfo::String => StringValue::coerce(self).map(Type::upcast),
+            TypeInfo::Color => ColorValue::coerce(self).map(Type::upcast),
+            TypeInfo::Frame => FrameValue::coerce(self).map(Type::upcast)
+        };
+        result.map_err(|value| format!("Expected a {:?} value, but got a {}", type_info, value.type_name()))
+    }
+
     pub fn type_name(&self) -> String {
         match self {
-            AnyType::FloatValue(_) => "flaot".to_string(),
+            AnyType::FloatValue(_) => "float".to_string(),
             AnyType::BoolValue(_) => "boolean".to_string(),
             AnyType::ColorValue(_) => "color".to_string(),
             AnyType::FrameValue(_) => "frame".to_string(),
-            AnyType::IntegerValue(_) => "integer".to_string()
+            AnyType::IntegerValue(_) => "integer".to_string(),
+            AnyType::StringValue(_) => "string".to_string()
         }
     }
 }
 
-/// Converts a vector of AnyType to a predefined tuple of specific types.  
+/// Converts a slice of AnyType to a predefined tuple of specific types.  
 /// Used to move error-checking from the nodes themselves to the evaluator.
 pub trait TryConvert<T> {
     type Output = T;
-    fn try_convert(self) -> Result<T, String>;
+    fn try_convert(&self) -> Result<T, String>;
 }
 
-/// Converts a specialized type to a generic vector of AnyType.
+/// Writes a specialized type to a slice of AnyType.
 /// Used to move error-checking from the nodes themselves to the evaluator.
 pub trait TryConvertBack {
-    fn try_convert_back(self) -> Vec<AnyType>;
+    fn try_convert_back(self, outputs: &mut [AnyType]) -> Result<(), String>;
 }
 
 #[macro_export]
 macro_rules! impl_try_convert {
     ($(($idx:tt, $name:ident)),+) => {
-        impl TryConvert<($($name,)*)> for VecDeque<AnyType> where
+        impl TryConvert<($($name,)*)> for [AnyType] where
             $($name: crate::effects::node_editor::types::Type),* {
-            fn try_convert(mut self) -> Result<Self::Output, String> {
+            fn try_convert(&self) -> Result<Self::Output, String> {
                 let value_count = [$(stringify!($name),)*].len();
                 if self.len() != value_count {
                     return Err(format!("Expected {} values, got {}", value_count, self.len()));
                 }
                 let result = ($(
-                    match self.pop_front() {
-                        Some(AnyType::$name(v)) => v,
-                        Some(v) => return Err(format!("Expected {}, got {}", stringify!($name), v.type_name())),
-                        None => unreachable!(),
+                    match <$name as crate::render::effects::node_editor::types::Type>::coerce(self[$idx].clone()) {
+                        Ok(v) => v,
+                        Err(v) => return Err(format!("Expected {}, got {}", stringify!($name), v.type_name())),
                     },
                 )*);
                 Ok(result)
             }
         }
         impl TryConvertBack for ($($name,)*) {
-            fn try_convert_back(self) -> Vec<AnyType> {
+            fn try_convert_back(self, outputs: &mut [AnyType]) -> Result<(), String> {
                 use crate::render::effects::node_editor::types::Type;
 
-                let mut result = Vec::new();
+                let value_count = [$(stringify!($name),)*].len();
+                if outputs.len() != value_count {
+                    return Err(format!("Expected {} outputs, got {}", value_count, outputs.len()));
+                }
                 $(
-                    result.push(self.$idx.upcast());
+                    outputs[$idx] = self.$idx.upcast();
                 )*
-                result
+                Ok(())
             }
         }
     };
@@ -160,8 +251,8 @@ macro_rules! impl_try_convert {
 }
 
 // Manual implementation for empty tuples
-impl TryConvert<()> for VecDeque<AnyType> {
-    fn try_convert(self) -> Result<Self::Output, String> {
+impl TryConvert<()> for [AnyType] {
+    fn try_convert(&self) -> Result<Self::Output, String> {
         if self.len() != 0 {
             return Err(format!("Expected 0 values, got {}", self.len()));
         }
@@ -174,9 +265,24 @@ impl_try_convert!(FloatValue, FloatValue);
 impl_try_convert!(FloatValue, FloatValue, FloatValue);
 impl_try_convert!(FloatValue, FloatValue, FloatValue, FloatValue);
 
+impl_try_convert!(FloatValue, BoolValue);
+
+impl_try_convert!(IntegerValue);
+impl_try_convert!(IntegerValue, IntegerValue);
+
+impl_try_convert!(StringValue);
+impl_try_convert!(StringValue, StringValue);
+
 impl_try_convert!(BoolValue);
 impl_try_convert!(BoolValue, BoolValue);
+impl_try_convert!(BoolValue, FloatValue, FloatValue);
+impl_try_convert!(BoolValue, ColorValue, ColorValue);
 
 impl_try_convert!(ColorValue);
 
-impl_try_convert!(ColorValue, ColorValue, FloatValue);
\ No newline at end of file
+impl_try_convert!(ColorValue, ColorValue, FloatValue);
+
+impl_try_convert!(FrameValue);
+impl_try_convert!(FrameValue, IntegerValue);
+impl_try_convert!(FrameValue, FrameValue);
+impl_try_convert!(FrameValue, FrameValue, FloatValue);
\ No newline at end of file
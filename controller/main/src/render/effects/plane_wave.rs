@@ -0,0 +1,79 @@
+use std::f64::consts::PI;
+
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::{Animatable, AnyColorExpression, ColorExpression}, frame::Frame}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// A sine wave that travels across the room in a straight line, blending between two colors.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct PlaneWaveEffect {
+    /// How far the wave has travelled, in meters. Accumulated every frame so changes in speed don't make the wave jump.
+    #[serde(skip)]
+    travelled: f64,
+    /// The color at the troughs of the wave.
+    color_a: AnyColorExpression,
+    /// The color at the crests of the wave.
+    color_b: AnyColorExpression,
+    /// The direction the wave travels in, in degrees. 0 points along the x axis and 90 along the y axis.
+    angle: Animatable<f64>,
+    /// The distance between crests, in meters.
+    wavelength: Animatable<f64>,
+    /// How fast the wave travels, in meters per second.
+    speed: Animatable<f64>
+}
+
+impl PlaneWaveEffect {
+    /// Creates a new plane wave effect.
+    #[allow(unused)]
+    pub fn new(
+        color_a: impl Into<AnyColorExpression>,
+        color_b: impl Into<AnyColorExpression>,
+        angle: impl Into<Animatable<f64>>,
+        wavelength: impl Into<Animatable<f64>>,
+        speed: impl Into<Animatable<f64>>
+    ) -> AnyEffect {
+        PlaneWaveEffect {
+            travelled: 0.,
+            color_a: color_a.into(),
+            color_b: color_b.into(),
+            angle: angle.into(),
+            wavelength: wavelength.into(),
+            speed: speed.into()
+        }.into()
+    }
+}
+
+impl Effect for PlaneWaveEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let expression_context = context.expression_context(render_info);
+        let (sin, cos) = self.angle.compute(&expression_context).to_radians().sin_cos();
+        let wavelength = self.wavelength.compute(&expression_context);
+        self.travelled += self.speed.compute(&expression_context) * context.delta.as_secs_f64();
+        let color_a = self.color_a.compute(&expression_context);
+        let color_b = self.color_b.compute(&expression_context);
+        if wavelength <= 0. {
+            return frame;
+        }
+
+        for (i, location) in render_info.pixel_locations.iter().enumerate().take(context.pixels as usize) {
+            let distance = location.x as f64 * cos + location.y as f64 * sin;
+            let wave = ((distance - self.travelled) / wavelength * 2. * PI).sin() * 0.5 + 0.5;
+            frame.set_pixel(i as u32, color_a.lerp(&color_b, wave));
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.color_a.fold_constants();
+        self.color_b.fold_constants();
+        self.angle.fold_constants();
+        self.wavelength.fold_constants();
+        self.speed.fold_constants();
+    }
+}
@@ -1,7 +1,7 @@
 use reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
-use crate::{render::{expressions::{AnyExpression, Expression}, frame::Frame}, RenderInfo};
+use crate::{render::{expressions::Animatable, frame::Frame}, RenderInfo};
 
 use super::{AnyEffect, Effect, RenderContext};
 
@@ -10,16 +10,16 @@ pub struct RotateEffect {
     /// The effect to rotate
     effect: Box<AnyEffect>,
     /// The number of pixels to rotate the frame by. If negative, it rotates to the left.
-    rotation: Box<AnyExpression>
+    rotation: Animatable<f64>
 }
 
 impl RotateEffect {
     /// Creates a new rotate effect.
     #[allow(unused)]
-    pub fn new(effect: Box<AnyEffect>, rotation: Box<AnyExpression>) -> AnyEffect {
+    pub fn new(effect: Box<AnyEffect>, rotation: impl Into<Animatable<f64>>) -> AnyEffect {
         RotateEffect {
             effect,
-            rotation
+            rotation: rotation.into()
         }.into()
     }
 }
@@ -29,7 +29,7 @@ impl Effect for RotateEffect {
         let rendered_frame = self.effect.render(context, render_info);
         
         let mut rotated_frame = Frame::empty(context.pixels);
-        let rot = self.rotation.compute(&context.expression_context());
+        let rot = self.rotation.compute(&context.expression_context(render_info));
         for i in 0..context.pixels {
             let new_i = (i as i32 - rot as i32).rem_euclid(context.pixels as i32) as usize;
             rotated_frame.set_pixel(new_i as u32, rendered_frame.get_pixel(i));
@@ -37,4 +37,9 @@ impl Effect for RotateEffect {
 
         rotated_frame
     }
+
+    fn optimize(&mut self) {
+        self.effect.optimize();
+        self.rotation.fold_constants();
+    }
 }
\ No newline at end of file
@@ -1,38 +1,48 @@
 use reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
-use crate::{render::frame::{self, PixelColor}, RenderInfo};
+use crate::{render::{expressions::{Animatable, AnyColorExpression, ColorExpression}, frame}, RenderInfo};
 
 use super::{AnyEffect, Effect, RenderContext};
 
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
 pub struct SolidColorEffect {
     /// The color to make every pixel.
-    color: PixelColor,
+    color: AnyColorExpression,
     /// The start pixel index.
-    start: u32,
+    start: Animatable<u32>,
     /// The stop pixel index.
-    stop: u32,
+    stop: Animatable<u32>,
 }
 
 impl SolidColorEffect {
     /// Creates a new solid color effect with the specified color, start, and stop.
     #[allow(unused)]
-    pub fn new(color: PixelColor, start: u32, stop: u32) -> AnyEffect {
+    pub fn new(color: impl Into<AnyColorExpression>, start: impl Into<Animatable<u32>>, stop: impl Into<Animatable<u32>>) -> AnyEffect {
         Self {
-            color, start, stop
+            color: color.into(), start: start.into(), stop: stop.into()
         }.into()
     }
 }
 
 impl Effect for SolidColorEffect {
-    fn render(&mut self, context: RenderContext, _render_info: &mut RenderInfo) -> frame::Frame {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> frame::Frame {
         let mut frame: frame::Frame = frame::Frame::empty(context.pixels);
 
-        for i in self.start..self.stop {
-            frame.set_pixel(i, self.color.clone());
+        let expression_context = context.expression_context(render_info);
+        let start = self.start.compute(&expression_context);
+        let stop = self.stop.compute(&expression_context).min(context.pixels);
+        let color = self.color.compute(&expression_context);
+        for i in start..stop {
+            frame.set_pixel(i, color.clone());
         }
 
         frame
     }
+
+    fn optimize(&mut self) {
+        self.color.fold_constants();
+        self.start.fold_constants();
+        self.stop.fold_constants();
+    }
 }
\ No newline at end of file
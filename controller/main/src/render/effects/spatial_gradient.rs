@@ -0,0 +1,132 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::Animatable, frame::Frame, gradient::Gradient}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// A gradient along a direction in the room, following the room's geometry instead of the strip's wiring.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct LinearGradientEffect {
+    /// The colors of the gradient. Wrapping gradients repeat along the direction.
+    gradient: Gradient,
+    /// The direction of the gradient, in degrees. 0 points along the x axis and 90 along the y axis.
+    angle: Animatable<f64>,
+    /// The distance the gradient spans from position 0 to 1, in meters.
+    length: Animatable<f64>,
+    /// How far the gradient is moved along its direction from the room's origin, in meters.
+    /// Increasing it over time makes the gradient travel.
+    offset: Animatable<f64>
+}
+
+impl LinearGradientEffect {
+    /// Creates a new linear gradient effect.
+    #[allow(unused)]
+    pub fn new(
+        gradient: Gradient,
+        angle: impl Into<Animatable<f64>>,
+        length: impl Into<Animatable<f64>>,
+        offset: impl Into<Animatable<f64>>
+    ) -> AnyEffect {
+        LinearGradientEffect {
+            gradient,
+            angle: angle.into(),
+            length: length.into(),
+            offset: offset.into()
+        }.into()
+    }
+}
+
+impl Effect for LinearGradientEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let expression_context = context.expression_context(render_info);
+        let (sin, cos) = self.angle.compute(&expression_context).to_radians().sin_cos();
+        let length = self.length.compute(&expression_context);
+        let offset = self.offset.compute(&expression_context);
+        let gradient = self.gradient.compute(&expression_context);
+        if length <= 0. {
+            return frame;
+        }
+
+        for (i, location) in render_info.pixel_locations.iter().enumerate().take(context.pixels as usize) {
+            let distance = location.x as f64 * cos + location.y as f64 * sin;
+            frame.set_pixel(i as u32, gradient.sample((distance - offset) / length));
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.angle.fold_constants();
+        self.length.fold_constants();
+        self.offset.fold_constants();
+        self.gradient.fold_constants();
+    }
+}
+
+/// A gradient outward from a point in the room.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct RadialGradientEffect {
+    /// The colors of the gradient from the center outward. Wrapping gradients repeat as rings.
+    gradient: Gradient,
+    /// The center of the gradient in the room, in meters.
+    center_x: Animatable<f64>,
+    center_y: Animatable<f64>,
+    /// The distance from the center to position 1 of the gradient, in meters.
+    radius: Animatable<f64>,
+    /// How far the gradient is moved outward, in meters. Increasing it over time makes rings expand from the center.
+    offset: Animatable<f64>
+}
+
+impl RadialGradientEffect {
+    /// Creates a new radial gradient effect.
+    #[allow(unused)]
+    pub fn new(
+        gradient: Gradient,
+        center_x: impl Into<Animatable<f64>>,
+        center_y: impl Into<Animatable<f64>>,
+        radius: impl Into<Animatable<f64>>,
+        offset: impl Into<Animatable<f64>>
+    ) -> AnyEffect {
+        RadialGradientEffect {
+            gradient,
+            center_x: center_x.into(),
+            center_y: center_y.into(),
+            radius: radius.into(),
+            offset: offset.into()
+        }.into()
+    }
+}
+
+impl Effect for RadialGradientEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let expression_context = context.expression_context(render_info);
+        let center_x = self.center_x.compute(&expression_context);
+        let center_y = self.center_y.compute(&expression_context);
+        let radius = self.radius.compute(&expression_context);
+        let offset = self.offset.compute(&expression_context);
+        let gradient = self.gradient.compute(&expression_context);
+        if radius <= 0. {
+            return frame;
+        }
+
+        for (i, location) in render_info.pixel_locations.iter().enumerate().take(context.pixels as usize) {
+            let distance = (location.x as f64 - center_x).hypot(location.y as f64 - center_y);
+            frame.set_pixel(i as u32, gradient.sample((distance - offset) / radius));
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.center_x.fold_constants();
+        self.center_y.fold_constants();
+        self.radius.fold_constants();
+        self.offset.fold_constants();
+        self.gradient.fold_constants();
+    }
+}
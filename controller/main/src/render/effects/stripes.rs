@@ -1,7 +1,7 @@
 use reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
-use crate::{render::frame::{Frame, PixelColor}, RenderInfo};
+use crate::{render::{expressions::Animatable, frame::{Frame, PixelColor}, gradient::{Gradient, GradientInterpolation}}, RenderInfo};
 
 use super::{AnyEffect, Effect, RenderContext};
 
@@ -9,38 +9,49 @@ use super::{AnyEffect, Effect, RenderContext};
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
 pub struct StripeEffect {
     /// The width of each stripe in pixels.
-    stripe_width: f64,
-    /// The colors of the stripes.
-    stripe_colors: Vec<PixelColor>
+    stripe_width: Animatable<f64>,
+    /// The colors of the stripes: one stripe for every stop, sampled evenly along the gradient.
+    stripe_colors: Gradient
 }
 
 impl StripeEffect {
     /// Creates a new stripes effect with the specified stripe width and colors.
     #[allow(unused)]
-    pub fn new(stripe_width: f64, stripe_colors: Vec<PixelColor>) -> AnyEffect {
+    pub fn new(stripe_width: impl Into<Animatable<f64>>, stripe_colors: Vec<PixelColor>) -> AnyEffect {
         StripeEffect {
-            stripe_width,
-            stripe_colors
+            stripe_width: stripe_width.into(),
+            stripe_colors: Gradient::evenly_spaced(stripe_colors, GradientInterpolation::RGB, false)
         }.into()
     }
 }
 
 impl Effect for StripeEffect {
-    fn render(&mut self, context: RenderContext, _render_info: &mut RenderInfo) -> Frame {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
         let mut frame = Frame::empty(context.pixels);
 
+        let expression_context = context.expression_context(render_info);
+        let stripe_width = self.stripe_width.compute(&expression_context);
+        let stripe_count = self.stripe_colors.stops.len();
+        if stripe_width <= 0. || stripe_count == 0 {
+            return frame;
+        }
+        let gradient = self.stripe_colors.compute(&expression_context);
+        let stripe_colors = (0..stripe_count)
+            .map(|index| gradient.sample_evenly(index, stripe_count))
+            .collect::<Vec<_>>();
+
         for i in 0..context.pixels {
             let stripe_pos = i as f64;
 
-            let stripe_index = (stripe_pos / self.stripe_width).floor() as usize % self.stripe_colors.len();
-            let stripe_color = &self.stripe_colors[stripe_index];
+            let stripe_index = (stripe_pos / stripe_width).floor() as usize % stripe_colors.len();
+            let stripe_color = &stripe_colors[stripe_index];
             let rgb = color_space::Rgb::new(
                 stripe_color.r as f64,
                 stripe_color.g as f64,
                 stripe_color.b as f64,
             );
 
-            let fade = 1. - (stripe_pos % self.stripe_width) / self.stripe_width;
+            let fade = 1. - (stripe_pos % stripe_width) / stripe_width;
 
             let mut pixel: PixelColor = rgb.into();
             pixel.alpha = fade * stripe_color.alpha;
@@ -50,4 +61,9 @@ impl Effect for StripeEffect {
 
         frame
     }
+
+    fn optimize(&mut self) {
+        self.stripe_width.fold_constants();
+        self.stripe_colors.fold_constants();
+    }
 }
\ No newline at end of file
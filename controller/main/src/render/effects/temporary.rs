@@ -3,6 +3,8 @@ use crate::render::{frame::Frame, RenderInfo};
 use super::{AnyTemporaryEffect, Effect, RenderContext, TemporaryEffect};
 
 pub mod duration;
+pub mod ripple;
+pub mod sunrise;
 
 /// A compositor for temporary effects.
 /// Manages the lifecycle of temporary effects by starting, running, stopping, and sequentially rendering
@@ -24,9 +26,15 @@ impl TemporaryEffectCompositor {
 
     /// Adds a new effect to the compositor.
     /// The effect will be rendered after all the other effects in the sequence.
-    pub fn add_effect(&mut self, effect: AnyTemporaryEffect) {
+    pub fn add_effect(&mut self, mut effect: AnyTemporaryEffect) {
+        effect.optimize();
         self.effects.push(Box::new(effect));
     }
+
+    /// The effects that are running or waiting to run, in order.
+    pub fn effects_mut(&mut self) -> impl Iterator<Item = &mut AnyTemporaryEffect> {
+        self.effects.iter_mut().map(|effect| effect.as_mut())
+    }
 }
 
 impl Effect for TemporaryEffectCompositor {
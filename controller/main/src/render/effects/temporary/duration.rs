@@ -31,6 +31,10 @@ impl Effect for DurationTemporaryEffect {
     fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> frame::Frame {
         self.effect.render(context, render_info)
     }
+
+    fn optimize(&mut self) {
+        self.effect.optimize();
+    }
 }
 
 impl TemporaryEffect for DurationTemporaryEffect {
@@ -0,0 +1,116 @@
+use std::f64::consts::PI;
+
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{effects::{AnyTemporaryEffect, Effect, RenderContext, TemporaryEffect}, expressions::{AnyColorExpression, ColorExpression}, frame::Frame, spatial_map::Location}, RenderInfo};
+
+/// The most rings a ripple can have, since every ring is checked for every pixel.
+static MAX_RINGS: u32 = 16;
+
+/// Rings of color that expand from a point in the room and fade out as they reach the farthest pixel.
+/// Finishes once the last ring has faded out.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct RippleTemporaryEffect {
+    /// The point the rings expand from.
+    origin: Location,
+    /// The color of the rings.
+    color: AnyColorExpression,
+    /// How fast the rings expand, in meters per second.
+    speed: f64,
+    /// The width of each ring, in meters.
+    width: f64,
+    /// The number of rings, up to 16.
+    rings: u32,
+    /// The time between rings, in seconds.
+    interval: f64,
+
+    // The time the effect started
+    #[serde(skip)]
+    start_time: f64,
+    // The distance from the origin to the farthest pixel, in meters
+    #[serde(skip)]
+    max_distance: f64
+}
+
+impl RippleTemporaryEffect {
+    /// Creates a new ripple effect.
+    #[allow(unused)]
+    pub fn new(origin: Location, color: impl Into<AnyColorExpression>, speed: f64, width: f64, rings: u32, interval: f64) -> AnyTemporaryEffect {
+        RippleTemporaryEffect {
+            origin,
+            color: color.into(),
+            speed,
+            width,
+            rings,
+            interval,
+            start_time: 0.,
+            max_distance: 0.
+        }.into()
+    }
+
+    fn distance(&self, location: &Location) -> f64 {
+        (location.x as f64 - self.origin.x as f64).hypot(location.y as f64 - self.origin.y as f64)
+    }
+
+    /// The radius of a ring after the effect has been running for `elapsed` seconds.
+    fn radius(&self, ring: u32, elapsed: f64) -> f64 {
+        (elapsed - ring as f64 * self.interval) * self.speed
+    }
+}
+
+impl Effect for RippleTemporaryEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+        if self.speed <= 0. || self.width <= 0. || self.max_distance <= 0. {
+            return frame;
+        }
+
+        let elapsed = render_info.time - self.start_time;
+        let color = self.color.compute(&context.expression_context(render_info));
+        let radii = (0..self.rings)
+            .map(|ring| self.radius(ring, elapsed))
+            .filter(|radius| *radius > -self.width / 2.)
+            .collect::<Vec<_>>();
+
+        for (i, location) in render_info.pixel_locations.iter().enumerate().take(context.pixels as usize) {
+            let distance = self.distance(location);
+            let brightness = radii.iter().map(|radius| {
+                let offset = (distance - radius).abs() / (self.width / 2.);
+                if offset >= 1. {
+                    return 0.;
+                }
+                let fade = (1. - radius.max(0.) / self.max_distance).max(0.);
+                ((offset * PI).cos() * 0.5 + 0.5) * fade
+            }).fold(0., f64::max);
+
+            if brightness > 0. {
+                frame.set_pixel(i as u32, color.with_alpha(color.alpha * brightness));
+            }
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.color.fold_constants();
+    }
+}
+
+impl TemporaryEffect for RippleTemporaryEffect {
+    fn start(&mut self, render_info: &mut RenderInfo) {
+        self.start_time = render_info.time;
+        self.rings = self.rings.min(MAX_RINGS);
+        self.max_distance = render_info.pixel_locations.iter()
+            .map(|location| self.distance(location))
+            .fold(0., f64::max);
+    }
+
+    fn is_finished(&self, render_info: &RenderInfo) -> bool {
+        let elapsed = render_info.time - self.start_time;
+        self.rings == 0 || self.speed <= 0. ||
+            self.radius(self.rings - 1, elapsed) - self.width / 2. >= self.max_distance
+    }
+
+    fn stop(&mut self, _render_info: &mut RenderInfo) {}
+}
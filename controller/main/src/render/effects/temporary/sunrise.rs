@@ -0,0 +1,101 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{effects::{AnyTemporaryEffect, Effect, RenderContext, TemporaryEffect}, frame::Frame}, RenderInfo};
+
+/// Fades the whole strip in from dark, warm light to bright, cool light, like a sunrise. Overrides everything under it.
+/// Once the sunrise is over, it stays at full brightness for a while before finishing, unless it's dismissed first.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct SunriseTemporaryEffect {
+    /// How long the sunrise takes, in seconds.
+    pub duration: f64,
+    /// How long the light stays on after the sunrise, in seconds.
+    pub hold: f64,
+    /// The color temperature at the start of the sunrise, in Kelvin.
+    pub start_temperature: f64,
+    /// The color temperature at the end of the sunrise, in Kelvin.
+    pub end_temperature: f64,
+    /// The brightness at the end of the sunrise, from 0 to 1.
+    pub brightness: f64,
+
+    // The time the effect started
+    #[serde(skip)]
+    start_time: f64,
+    // The time the effect is snoozed until, if it was snoozed
+    #[serde(skip)]
+    snoozed_until: Option<f64>,
+    #[serde(skip)]
+    dismissed: bool
+}
+
+impl SunriseTemporaryEffect {
+    /// Creates a new sunrise effect.
+    #[allow(unused)]
+    pub fn new(duration: f64, hold: f64, start_temperature: f64, end_temperature: f64, brightness: f64) -> AnyTemporaryEffect {
+        SunriseTemporaryEffect {
+            duration,
+            hold,
+            start_temperature,
+            end_temperature,
+            brightness,
+            start_time: 0.,
+            snoozed_until: None,
+            dismissed: false
+        }.into()
+    }
+
+    /// Turns the light off until `until`, in render time. After that, it comes back on at full brightness.
+    pub fn snooze(&mut self, until: f64) {
+        self.snoozed_until = Some(until);
+    }
+
+    /// Finishes the effect on the next frame.
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+
+    /// The time the light reached full brightness, either at the end of the sunrise or the end of the last snooze.
+    fn full_brightness_time(&self) -> f64 {
+        let sunrise_end = self.start_time + self.duration;
+        self.snoozed_until.map_or(sunrise_end, |until| until.max(sunrise_end))
+    }
+}
+
+impl Effect for SunriseTemporaryEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+        if self.dismissed {
+            return frame;
+        }
+
+        // The light stays off while snoozed
+        let progress = if self.snoozed_until.is_some_and(|until| render_info.time < until) {
+            0.
+        } else if self.snoozed_until.is_some() || self.duration <= 0. {
+            1.
+        } else {
+            ((render_info.time - self.start_time) / self.duration).clamp(0., 1.)
+        };
+        let temperature = self.start_temperature + (self.end_temperature - self.start_temperature) * progress;
+        // Black is drawn at the start too, so the sunrise starts from darkness
+        let color = render_info.white_calibration.white(temperature, self.brightness.clamp(0., 1.) * progress);
+
+        for i in 0..context.pixels {
+            frame.set_pixel(i, color.clone());
+        }
+
+        frame
+    }
+}
+
+impl TemporaryEffect for SunriseTemporaryEffect {
+    fn start(&mut self, render_info: &mut RenderInfo) {
+        self.start_time = render_info.time;
+    }
+
+    fn is_finished(&self, render_info: &RenderInfo) -> bool {
+        self.dismissed || render_info.time >= self.full_brightness_time() + self.hold
+    }
+
+    fn stop(&mut self, _render_info: &mut RenderInfo) {}
+}
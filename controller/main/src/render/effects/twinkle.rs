@@ -0,0 +1,119 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::Animatable, frame::Frame, gradient::Gradient, random::SeededRng}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// A pixel that's currently twinkling.
+#[derive(Clone, Copy, Debug)]
+struct Twinkle {
+    /// The time since the twinkle started, in seconds.
+    age: f64,
+    /// The position of the twinkle's color in the palette.
+    color: f64
+}
+
+/// Random pixels fade in and out with colors from a palette. Pixels that aren't twinkling are transparent.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct TwinkleEffect {
+    /// The average fraction of pixels twinkling at once, from 0 to 1.
+    density: Animatable<f64>,
+    /// How long a twinkle takes to fade in, in seconds.
+    fade_in: Animatable<f64>,
+    /// How long a twinkle takes to fade out, in seconds.
+    fade_out: Animatable<f64>,
+    /// The colors of the twinkles. Every twinkle picks a random position in the palette.
+    palette: Gradient,
+    /// The seed for which pixels twinkle, so the effect looks the same every time it runs.
+    seed: u32,
+
+    #[serde(skip)]
+    twinkles: Vec<Option<Twinkle>>,
+    #[serde(skip)]
+    rng: Option<SeededRng>
+}
+
+impl TwinkleEffect {
+    /// Creates a new twinkle effect.
+    #[allow(unused)]
+    pub fn new(
+        density: impl Into<Animatable<f64>>,
+        fade_in: impl Into<Animatable<f64>>,
+        fade_out: impl Into<Animatable<f64>>,
+        palette: Gradient,
+        seed: u32
+    ) -> AnyEffect {
+        TwinkleEffect {
+            density: density.into(),
+            fade_in: fade_in.into(),
+            fade_out: fade_out.into(),
+            palette,
+            seed,
+            twinkles: vec![],
+            rng: None
+        }.into()
+    }
+}
+
+impl Effect for TwinkleEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let expression_context = context.expression_context(render_info);
+        let density = self.density.compute(&expression_context).clamp(0., 0.99);
+        let fade_in = self.fade_in.compute(&expression_context).max(0.);
+        let fade_out = self.fade_out.compute(&expression_context).max(0.);
+        if self.palette.stops.is_empty() {
+            return frame;
+        }
+        let palette = self.palette.compute(&expression_context);
+
+        let delta = context.delta.as_secs_f64();
+        let lifetime = (fade_in + fade_out).max(delta);
+        // Twinkles start at a rate that keeps the average fraction of twinkling pixels at the density
+        let rate = density / ((1. - density) * lifetime);
+        let start_chance = 1. - (-rate * delta).exp();
+
+        self.twinkles.resize(context.pixels as usize, None);
+        let seed = self.seed as u64;
+        let rng = self.rng.get_or_insert_with(|| SeededRng::new(seed));
+        for (i, twinkle) in self.twinkles.iter_mut().enumerate() {
+            match twinkle {
+                Some(current) => {
+                    current.age += delta;
+                    if current.age >= lifetime {
+                        *twinkle = None;
+                    }
+                }
+                None => if rng.next_f64() < start_chance {
+                    *twinkle = Some(Twinkle {
+                        age: 0.,
+                        color: rng.next_f64()
+                    });
+                }
+            }
+
+            if let Some(twinkle) = twinkle {
+                let brightness = if twinkle.age < fade_in {
+                    twinkle.age / fade_in
+                } else if fade_out > 0. {
+                    1. - (twinkle.age - fade_in) / fade_out
+                } else {
+                    1.
+                };
+                let color = palette.sample(twinkle.color);
+                frame.set_pixel(i as u32, color.with_alpha(color.alpha * brightness.clamp(0., 1.)));
+            }
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.density.fold_constants();
+        self.fade_in.fold_constants();
+        self.fade_out.fold_constants();
+        self.palette.fold_constants();
+    }
+}
@@ -0,0 +1,48 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::{render::{expressions::Animatable, frame::Frame}, RenderInfo};
+
+use super::{AnyEffect, Effect, RenderContext};
+
+/// Fills the strip with white light at a color temperature, corrected with the installation's white calibration
+/// so it matches light bulbs of the same temperature.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct WhiteEffect {
+    /// The color temperature, in Kelvin. Warm white bulbs are around 2700K and daylight is around 6500K.
+    temperature: Animatable<f64>,
+    /// The brightness, from 0 to 1.
+    brightness: Animatable<f64>
+}
+
+impl WhiteEffect {
+    /// Creates a new white effect with the specified color temperature and brightness.
+    #[allow(unused)]
+    pub fn new(temperature: impl Into<Animatable<f64>>, brightness: impl Into<Animatable<f64>>) -> AnyEffect {
+        WhiteEffect {
+            temperature: temperature.into(),
+            brightness: brightness.into()
+        }.into()
+    }
+}
+
+impl Effect for WhiteEffect {
+    fn render(&mut self, context: RenderContext, render_info: &mut RenderInfo) -> Frame {
+        let mut frame = Frame::empty(context.pixels);
+
+        let expression_context = context.expression_context(render_info);
+        let temperature = self.temperature.compute(&expression_context);
+        let brightness = self.brightness.compute(&expression_context);
+        let color = render_info.white_calibration.white(temperature, brightness);
+        for i in 0..context.pixels {
+            frame.set_pixel(i, color.clone());
+        }
+
+        frame
+    }
+
+    fn optimize(&mut self) {
+        self.temperature.fold_constants();
+        self.brightness.fold_constants();
+    }
+}
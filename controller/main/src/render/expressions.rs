@@ -1,18 +1,54 @@
 use enum_dispatch::enum_dispatch;
 use reflection::Reflect;
+pub use animatable::Animatable;
 pub use arithmetic::{AddExpression, DivideExpression, MultiplyExpression, SubtractExpression};
+pub use conditional::SelectExpression;
+pub use interpolation::{EaseExpression, EasingCurve, LerpExpression, SmoothstepExpression};
+pub use keyframes::{Keyframe, KeyframeMode, KeyframesExpression};
+pub use noise::{NoiseExpression, NoiseKind, RandomExpression};
+pub use math::{AbsExpression, ClampExpression, CosExpression, MaxExpression, MinExpression, ModuloExpression, PowerExpression, SinExpression};
 pub use round::{CeilExpression, FloorExpression, RoundExpression};
-pub use values::{CurrentTimeExpression, LiteralExpression};
-use serde::{Deserialize, Serialize};
+pub use values::{CurrentTimeExpression, FrameCountExpression, LiteralExpression, PixelIndexExpression, PixelPositionExpression, PixelXExpression, PixelYExpression};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+pub use variable::VariableExpression;
+pub use audio::{AudioExpression, AudioValue};
+pub use color::{AnyColorExpression, ColorExpression, ConstantColorExpression};
 
+use super::{audio::AudioData, variables::Variables};
+pub use syntax::parse;
+
+mod animatable;
 mod values;
+mod variable;
+mod audio;
 mod arithmetic;
 mod round;
+mod math;
+mod interpolation;
+mod keyframes;
+mod conditional;
+mod noise;
+mod color;
+mod syntax;
+mod simplify;
 
 #[derive(Debug, Clone, Copy)]
-pub struct ExpressionContext {
+pub struct ExpressionContext<'a> {
     /// The current time, in seconds.
-    pub current_time: f64
+    pub current_time: f64,
+    /// The number of frames rendered so far.
+    pub frame: usize,
+    /// The index of the pixel being computed. Expressions computed once per frame see pixel 0.
+    pub pixel: u32,
+    /// The pixel's position along the strip, from 0 to 1.
+    pub position: f64,
+    /// The pixel's location in the room, in meters.
+    pub x: f64,
+    pub y: f64,
+    /// The variables set at runtime.
+    pub variables: &'a Variables,
+    /// The latest audio data from the music visualizer.
+    pub audio: &'a AudioData
 }
 
 /// An expression is a tree of constructs that returns a number.
@@ -20,22 +56,75 @@ pub struct ExpressionContext {
 #[enum_dispatch]
 pub trait Expression {
     fn compute<'a>(&mut self, context: &'a ExpressionContext) -> f64;
+
+    /// The expressions this expression takes as inputs.
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![]
+    }
+
+    /// Whether the result depends on more than the inputs, like the time or the pixel being computed.
+    /// Expressions that don't are folded into a constant when all of their inputs are constant.
+    fn depends_on_context(&self) -> bool {
+        false
+    }
 }
 
 /// A wrapper for any expression.
 /// Used for serialization and deserialization.
+/// Expressions can also be deserialized from their text form, like `"time * 84"`, or from a plain number.
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(remote = "Self")]
 #[serde(tag = "type")]
 #[reflect(export_runtime_schema)]
 #[enum_dispatch(Expression)]
 pub enum AnyExpression {
     Number(LiteralExpression),
     CurrentTime(CurrentTimeExpression),
+    FrameCount(FrameCountExpression),
+    PixelIndex(PixelIndexExpression),
+    PixelPosition(PixelPositionExpression),
+    PixelX(PixelXExpression),
+    PixelY(PixelYExpression),
+    Variable(VariableExpression),
+    Audio(AudioExpression),
     Add(AddExpression),
     Subtract(SubtractExpression),
     Multiply(MultiplyExpression),
     Divide(DivideExpression),
     Round(RoundExpression),
     Floor(FloorExpression),
-    Ceil(CeilExpression)
+    Ceil(CeilExpression),
+    Sin(SinExpression),
+    Cos(CosExpression),
+    Abs(AbsExpression),
+    Min(MinExpression),
+    Max(MaxExpression),
+    Clamp(ClampExpression),
+    Modulo(ModuloExpression),
+    Power(PowerExpression),
+    Lerp(LerpExpression),
+    Smoothstep(SmoothstepExpression),
+    Ease(EaseExpression),
+    Keyframes(KeyframesExpression),
+    Select(SelectExpression),
+    Random(RandomExpression),
+    Noise(NoiseExpression)
+}
+
+impl Serialize for AnyExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AnyExpression::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AnyExpression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(text) => parse(&text).map_err(D::Error::custom),
+            serde_json::Value::Number(number) => number.as_f64()
+                .map(LiteralExpression::new)
+                .ok_or_else(|| D::Error::custom("Invalid number")),
+            value => AnyExpression::deserialize(value).map_err(D::Error::custom)
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,100 @@
+use std::marker::PhantomData;
+
+use reflection::{schema, Reflect, TypeVisitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{AnyExpression, Expression, ExpressionContext, LiteralExpression};
+
+/// A type an animatable parameter can be computed as.
+pub trait AnimatableValue: Copy {
+    fn from_number(value: f64) -> Self;
+    fn to_number(self) -> f64;
+}
+
+impl AnimatableValue for f64 {
+    fn from_number(value: f64) -> Self {
+        value
+    }
+    fn to_number(self) -> f64 {
+        self
+    }
+}
+
+/// Rounded to the nearest whole number; negative values become 0.
+impl AnimatableValue for u32 {
+    fn from_number(value: f64) -> Self {
+        value.round() as u32
+    }
+    fn to_number(self) -> f64 {
+        self as f64
+    }
+}
+
+/// An effect parameter that can be a plain number or an expression, so any parameter can be animated.
+/// Plain numbers are deserialized as literal expressions, so effects saved before a parameter was
+/// animatable still load.
+#[derive(Clone, Debug)]
+pub struct Animatable<T: AnimatableValue> {
+    expression: AnyExpression,
+    value_type: PhantomData<T>
+}
+
+impl<T: AnimatableValue> Animatable<T> {
+    pub fn compute(&mut self, context: &ExpressionContext) -> T {
+        return T::from_number(self.expression.compute(context));
+    }
+
+    pub fn fold_constants(&mut self) {
+        self.expression.fold_constants();
+    }
+}
+
+impl<T: AnimatableValue> From<T> for Animatable<T> {
+    fn from(value: T) -> Self {
+        LiteralExpression::new(value.to_number()).into()
+    }
+}
+
+impl<T: AnimatableValue> From<AnyExpression> for Animatable<T> {
+    fn from(expression: AnyExpression) -> Self {
+        Animatable {
+            expression,
+            value_type: PhantomData
+        }
+    }
+}
+
+impl<T: AnimatableValue> Serialize for Animatable<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        <AnyExpression as Serialize>::serialize(&self.expression, serializer)
+    }
+}
+
+impl<'de, T: AnimatableValue> Deserialize<'de> for Animatable<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <AnyExpression as Deserialize>::deserialize(deserializer).map(Animatable::from)
+    }
+}
+
+/// Animatable parameters are edited like any other expression.
+impl<T: AnimatableValue + 'static> Reflect for Animatable<T> {
+    const INLINE: bool = true;
+
+    fn ts_definition() -> String {
+        AnyExpression::ts_definition()
+    }
+    fn noninline_ts_definition() -> String {
+        AnyExpression::noninline_ts_definition()
+    }
+
+    fn schema() -> schema::Schema {
+        AnyExpression::schema()
+    }
+    fn schema_reference() -> schema::Schema {
+        AnyExpression::schema_reference()
+    }
+
+    fn visit_dependencies(visitor: &mut impl TypeVisitor) where Self: 'static {
+        visitor.visit_export::<AnyExpression>();
+    }
+}
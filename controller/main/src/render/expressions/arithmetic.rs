@@ -23,6 +23,10 @@ impl Expression for AddExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return self.left.compute(&context) + self.right.compute(&context);
     }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.left, &mut *self.right]
+    }
 }
 
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
@@ -45,6 +49,10 @@ impl Expression for DivideExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return self.left.compute(&context) / self.right.compute(&context);
     }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.left, &mut *self.right]
+    }
 }
 
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
@@ -67,6 +75,10 @@ impl Expression for MultiplyExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return self.left.compute(&context) * self.right.compute(&context);
     }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.left, &mut *self.right]
+    }
 }
 
 
@@ -90,4 +102,8 @@ impl Expression for SubtractExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return self.left.compute(&context) - self.right.compute(&context);
     }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.left, &mut *self.right]
+    }
 }
\ No newline at end of file
@@ -0,0 +1,63 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use super::{AnyExpression, Expression};
+
+/// A value computed from the latest audio data, from 0 to 1.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum AudioValue {
+    /// The average level over the whole spectrum.
+    Level,
+    Bass,
+    Mid,
+    Treble,
+    /// Jumps to 1 on every beat and quickly decays back to 0.
+    Beat
+}
+
+impl AudioValue {
+    pub const ALL: [AudioValue; 5] = [AudioValue::Level, AudioValue::Bass, AudioValue::Mid, AudioValue::Treble, AudioValue::Beat];
+
+    /// The name of the value in the text form of expressions.
+    pub fn variable_name(&self) -> &'static str {
+        match self {
+            AudioValue::Level => "level",
+            AudioValue::Bass => "bass",
+            AudioValue::Mid => "mid",
+            AudioValue::Treble => "treble",
+            AudioValue::Beat => "beat"
+        }
+    }
+}
+
+/// Gets a value from the latest audio data published by the music visualizer.
+/// Values fall to 0 when no music visualizer is running.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct AudioExpression {
+    pub value: AudioValue
+}
+
+impl AudioExpression {
+    #[allow(unused)]
+    pub fn new(value: AudioValue) -> AnyExpression {
+        AnyExpression::Audio(AudioExpression { value })
+    }
+}
+
+impl Expression for AudioExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        let audio = context.audio;
+        return match self.value {
+            AudioValue::Level => audio.level,
+            AudioValue::Bass => audio.bass,
+            AudioValue::Mid => audio.mid,
+            AudioValue::Treble => audio.treble,
+            AudioValue::Beat => audio.beat
+        };
+    }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,233 @@
+use enum_dispatch::enum_dispatch;
+use reflection::Reflect;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::render::{frame::PixelColor, gradient::Gradient};
+
+use super::{AnyExpression, Expression, ExpressionContext};
+
+/// A color expression is a tree of constructs that returns a color.
+/// Color expressions take number expressions and other color expressions as inputs.
+#[enum_dispatch]
+pub trait ColorExpression {
+    fn compute<'a>(&mut self, context: &'a ExpressionContext) -> PixelColor;
+
+    /// The number expressions this color expression takes as inputs.
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![]
+    }
+
+    /// The color expressions this color expression takes as inputs.
+    fn color_inputs(&mut self) -> Vec<&mut AnyColorExpression> {
+        vec![]
+    }
+}
+
+/// A wrapper for any color expression.
+/// Used for serialization and deserialization.
+/// A plain color, like `{ "r": 255, "g": 0, "b": 0, "alpha": 1 }`, is deserialized as a constant.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(remote = "Self")]
+#[serde(tag = "type")]
+#[reflect(export_runtime_schema)]
+#[enum_dispatch(ColorExpression)]
+pub enum AnyColorExpression {
+    Constant(ConstantColorExpression),
+    RGB(RGBColorExpression),
+    HSL(HSLColorExpression),
+    Gradient(GradientColorExpression),
+    Lerp(LerpColorExpression)
+}
+
+impl Serialize for AnyColorExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AnyColorExpression::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AnyColorExpression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if value.get("type").is_none() {
+            return PixelColor::deserialize(value).map(AnyColorExpression::from).map_err(D::Error::custom);
+        }
+        AnyColorExpression::deserialize(value).map_err(D::Error::custom)
+    }
+}
+
+impl From<PixelColor> for AnyColorExpression {
+    fn from(color: PixelColor) -> Self {
+        ConstantColorExpression::new(color)
+    }
+}
+
+/// A constant color.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ConstantColorExpression {
+    pub color: PixelColor
+}
+
+impl ConstantColorExpression {
+    #[allow(unused)]
+    pub fn new(color: PixelColor) -> AnyColorExpression {
+        AnyColorExpression::Constant(ConstantColorExpression { color })
+    }
+}
+
+impl ColorExpression for ConstantColorExpression {
+    fn compute<'a>(&mut self, _context: &'a ExpressionContext) -> PixelColor {
+        return self.color.clone();
+    }
+}
+
+/// Computes the alpha of a color from an optional expression, clamped from 0 to 1. Defaults to fully opaque.
+fn compute_alpha(alpha: &mut Option<Box<AnyExpression>>, context: &ExpressionContext) -> f64 {
+    return alpha.as_mut().map_or(1., |alpha| alpha.compute(context).clamp(0., 1.));
+}
+
+/// A color from expressions for its red, green, and blue channels, from 0 to 1.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct RGBColorExpression {
+    pub red: Box<AnyExpression>,
+    pub green: Box<AnyExpression>,
+    pub blue: Box<AnyExpression>,
+    /// The alpha of the color, from 0 to 1. Defaults to fully opaque.
+    pub alpha: Option<Box<AnyExpression>>
+}
+
+impl RGBColorExpression {
+    #[allow(unused)]
+    pub fn new(red: AnyExpression, green: AnyExpression, blue: AnyExpression, alpha: Option<AnyExpression>) -> AnyColorExpression {
+        AnyColorExpression::RGB(RGBColorExpression {
+            red: Box::new(red),
+            green: Box::new(green),
+            blue: Box::new(blue),
+            alpha: alpha.map(Box::new)
+        })
+    }
+}
+
+impl ColorExpression for RGBColorExpression {
+    fn compute<'a>(&mut self, context: &'a ExpressionContext) -> PixelColor {
+        let channel = |value: f64| (value.clamp(0., 1.) * 255.).round() as u8;
+        return PixelColor::new(
+            channel(self.red.compute(context)),
+            channel(self.green.compute(context)),
+            channel(self.blue.compute(context)),
+            compute_alpha(&mut self.alpha, context)
+        );
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        let mut inputs = vec![&mut *self.red, &mut *self.green, &mut *self.blue];
+        inputs.extend(self.alpha.as_deref_mut());
+        inputs
+    }
+}
+
+/// A color from expressions for its hue, saturation, and lightness.
+/// Hue is in degrees and wraps around; saturation and lightness are from 0 to 1.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct HSLColorExpression {
+    pub hue: Box<AnyExpression>,
+    pub saturation: Box<AnyExpression>,
+    pub lightness: Box<AnyExpression>,
+    /// The alpha of the color, from 0 to 1. Defaults to fully opaque.
+    pub alpha: Option<Box<AnyExpression>>
+}
+
+impl HSLColorExpression {
+    #[allow(unused)]
+    pub fn new(hue: AnyExpression, saturation: AnyExpression, lightness: AnyExpression, alpha: Option<AnyExpression>) -> AnyColorExpression {
+        AnyColorExpression::HSL(HSLColorExpression {
+            hue: Box::new(hue),
+            saturation: Box::new(saturation),
+            lightness: Box::new(lightness),
+            alpha: alpha.map(Box::new)
+        })
+    }
+}
+
+impl ColorExpression for HSLColorExpression {
+    fn compute<'a>(&mut self, context: &'a ExpressionContext) -> PixelColor {
+        return PixelColor::from_hsl(
+            self.hue.compute(context).rem_euclid(360.),
+            self.saturation.compute(context).clamp(0., 1.),
+            self.lightness.compute(context).clamp(0., 1.),
+            compute_alpha(&mut self.alpha, context)
+        );
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        let mut inputs = vec![&mut *self.hue, &mut *self.saturation, &mut *self.lightness];
+        inputs.extend(self.alpha.as_deref_mut());
+        inputs
+    }
+}
+
+/// Looks up a color in a gradient. With a wrapping gradient, a position that increases over time cycles through the colors forever.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct GradientColorExpression {
+    pub gradient: Gradient,
+    pub position: Box<AnyExpression>
+}
+
+impl GradientColorExpression {
+    #[allow(unused)]
+    pub fn new(gradient: Gradient, position: AnyExpression) -> AnyColorExpression {
+        AnyColorExpression::Gradient(GradientColorExpression {
+            gradient,
+            position: Box::new(position)
+        })
+    }
+}
+
+impl ColorExpression for GradientColorExpression {
+    fn compute<'a>(&mut self, context: &'a ExpressionContext) -> PixelColor {
+        let position = self.position.compute(context);
+        return self.gradient.sample(context, position);
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.position]
+    }
+
+    fn color_inputs(&mut self) -> Vec<&mut AnyColorExpression> {
+        self.gradient.stop_colors_mut().collect()
+    }
+}
+
+/// Blends between two colors. `t` is clamped from 0 to 1.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct LerpColorExpression {
+    pub start: Box<AnyColorExpression>,
+    pub end: Box<AnyColorExpression>,
+    pub t: Box<AnyExpression>
+}
+
+impl LerpColorExpression {
+    #[allow(unused)]
+    pub fn new(start: AnyColorExpression, end: AnyColorExpression, t: AnyExpression) -> AnyColorExpression {
+        AnyColorExpression::Lerp(LerpColorExpression {
+            start: Box::new(start),
+            end: Box::new(end),
+            t: Box::new(t)
+        })
+    }
+}
+
+impl ColorExpression for LerpColorExpression {
+    fn compute<'a>(&mut self, context: &'a ExpressionContext) -> PixelColor {
+        let start = self.start.compute(context);
+        let end = self.end.compute(context);
+        return start.lerp(&end, self.t.compute(context).clamp(0., 1.));
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.t]
+    }
+
+    fn color_inputs(&mut self) -> Vec<&mut AnyColorExpression> {
+        vec![&mut *self.start, &mut *self.end]
+    }
+}
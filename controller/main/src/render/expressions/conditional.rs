@@ -0,0 +1,37 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use super::{AnyExpression, Expression};
+
+/// Picks between two expressions: `if_true` when the condition is greater than 0, and `if_false` otherwise.
+/// Only the chosen expression is computed.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct SelectExpression {
+    pub condition: Box<AnyExpression>,
+    pub if_true: Box<AnyExpression>,
+    pub if_false: Box<AnyExpression>
+}
+
+impl SelectExpression {
+    #[allow(unused)]
+    pub fn new(condition: AnyExpression, if_true: AnyExpression, if_false: AnyExpression) -> AnyExpression {
+        AnyExpression::Select(SelectExpression {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false)
+        })
+    }
+}
+
+impl Expression for SelectExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        if self.condition.compute(context) > 0. {
+            return self.if_true.compute(context);
+        }
+        return self.if_false.compute(context);
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.condition, &mut *self.if_true, &mut *self.if_false]
+    }
+}
@@ -0,0 +1,224 @@
+use std::f64::consts::PI;
+
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use super::{AnyExpression, Expression};
+
+/// Linearly interpolates between two numbers. `t` isn't clamped, so values outside 0-1 extrapolate.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct LerpExpression {
+    pub start: Box<AnyExpression>,
+    pub end: Box<AnyExpression>,
+    pub t: Box<AnyExpression>
+}
+
+impl LerpExpression {
+    #[allow(unused)]
+    pub fn new(start: AnyExpression, end: AnyExpression, t: AnyExpression) -> AnyExpression {
+        AnyExpression::Lerp(LerpExpression {
+            start: Box::new(start),
+            end: Box::new(end),
+            t: Box::new(t)
+        })
+    }
+}
+
+impl Expression for LerpExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        let start = self.start.compute(context);
+        let end = self.end.compute(context);
+        return start + (end - start) * self.t.compute(context);
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.start, &mut *self.end, &mut *self.t]
+    }
+}
+
+/// Smoothly goes from 0 to 1 as the number goes from `start` to `end`.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct SmoothstepExpression {
+    pub start: Box<AnyExpression>,
+    pub end: Box<AnyExpression>,
+    pub number: Box<AnyExpression>
+}
+
+impl SmoothstepExpression {
+    #[allow(unused)]
+    pub fn new(start: AnyExpression, end: AnyExpression, number: AnyExpression) -> AnyExpression {
+        AnyExpression::Smoothstep(SmoothstepExpression {
+            start: Box::new(start),
+            end: Box::new(end),
+            number: Box::new(number)
+        })
+    }
+}
+
+impl Expression for SmoothstepExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        let start = self.start.compute(context);
+        let end = self.end.compute(context);
+        let t = ((self.number.compute(context) - start) / (end - start)).clamp(0., 1.);
+        return t * t * (3. - 2. * t);
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.start, &mut *self.end, &mut *self.number]
+    }
+}
+
+/// A standard easing curve, mapping 0-1 to 0-1.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum EasingCurve {
+    Linear,
+    InQuad,
+    OutQuad,
+    InOutQuad,
+    InCubic,
+    OutCubic,
+    InOutCubic,
+    InSine,
+    OutSine,
+    InOutSine,
+    InExpo,
+    OutExpo,
+    InOutExpo,
+    InBack,
+    OutBack,
+    InOutBack,
+    InBounce,
+    OutBounce,
+    InOutBounce
+}
+
+impl EasingCurve {
+    pub const ALL: [EasingCurve; 19] = [
+        EasingCurve::Linear,
+        EasingCurve::InQuad, EasingCurve::OutQuad, EasingCurve::InOutQuad,
+        EasingCurve::InCubic, EasingCurve::OutCubic, EasingCurve::InOutCubic,
+        EasingCurve::InSine, EasingCurve::OutSine, EasingCurve::InOutSine,
+        EasingCurve::InExpo, EasingCurve::OutExpo, EasingCurve::InOutExpo,
+        EasingCurve::InBack, EasingCurve::OutBack, EasingCurve::InOutBack,
+        EasingCurve::InBounce, EasingCurve::OutBounce, EasingCurve::InOutBounce
+    ];
+
+    /// The name of the curve's function in the text form of expressions, like `ease_in_out_quad`.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            EasingCurve::Linear => "ease_linear",
+            EasingCurve::InQuad => "ease_in_quad",
+            EasingCurve::OutQuad => "ease_out_quad",
+            EasingCurve::InOutQuad => "ease_in_out_quad",
+            EasingCurve::InCubic => "ease_in_cubic",
+            EasingCurve::OutCubic => "ease_out_cubic",
+            EasingCurve::InOutCubic => "ease_in_out_cubic",
+            EasingCurve::InSine => "ease_in_sine",
+            EasingCurve::OutSine => "ease_out_sine",
+            EasingCurve::InOutSine => "ease_in_out_sine",
+            EasingCurve::InExpo => "ease_in_expo",
+            EasingCurve::OutExpo => "ease_out_expo",
+            EasingCurve::InOutExpo => "ease_in_out_expo",
+            EasingCurve::InBack => "ease_in_back",
+            EasingCurve::OutBack => "ease_out_back",
+            EasingCurve::InOutBack => "ease_in_out_back",
+            EasingCurve::InBounce => "ease_in_bounce",
+            EasingCurve::OutBounce => "ease_out_bounce",
+            EasingCurve::InOutBounce => "ease_in_out_bounce"
+        }
+    }
+
+    /// Applies the curve. `t` is clamped to 0-1 first.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0., 1.);
+        // Constants for the "back" curves, which overshoot by about 10%
+        const C1: f64 = 1.70158;
+        const C2: f64 = C1 * 1.525;
+        const C3: f64 = C1 + 1.;
+
+        match self {
+            EasingCurve::Linear => t,
+            EasingCurve::InQuad => t * t,
+            EasingCurve::OutQuad => 1. - (1. - t) * (1. - t),
+            EasingCurve::InOutQuad => if t < 0.5 { 2. * t * t } else { 1. - (-2. * t + 2.).powi(2) / 2. },
+            EasingCurve::InCubic => t * t * t,
+            EasingCurve::OutCubic => 1. - (1. - t).powi(3),
+            EasingCurve::InOutCubic => if t < 0.5 { 4. * t * t * t } else { 1. - (-2. * t + 2.).powi(3) / 2. },
+            EasingCurve::InSine => 1. - (t * PI / 2.).cos(),
+            EasingCurve::OutSine => (t * PI / 2.).sin(),
+            EasingCurve::InOutSine => -((PI * t).cos() - 1.) / 2.,
+            EasingCurve::InExpo => if t == 0. { 0. } else { 2f64.powf(10. * t - 10.) },
+            EasingCurve::OutExpo => if t == 1. { 1. } else { 1. - 2f64.powf(-10. * t) },
+            EasingCurve::InOutExpo => {
+                if t == 0. || t == 1. {
+                    t
+                } else if t < 0.5 {
+                    2f64.powf(20. * t - 10.) / 2.
+                } else {
+                    (2. - 2f64.powf(-20. * t + 10.)) / 2.
+                }
+            }
+            EasingCurve::InBack => C3 * t * t * t - C1 * t * t,
+            EasingCurve::OutBack => 1. + C3 * (t - 1.).powi(3) + C1 * (t - 1.).powi(2),
+            EasingCurve::InOutBack => {
+                if t < 0.5 {
+                    (2. * t).powi(2) * ((C2 + 1.) * 2. * t - C2) / 2.
+                } else {
+                    ((2. * t - 2.).powi(2) * ((C2 + 1.) * (t * 2. - 2.) + C2) + 2.) / 2.
+                }
+            }
+            EasingCurve::InBounce => 1. - EasingCurve::OutBounce.apply(1. - t),
+            EasingCurve::OutBounce => {
+                const N1: f64 = 7.5625;
+                const D1: f64 = 2.75;
+                if t < 1. / D1 {
+                    N1 * t * t
+                } else if t < 2. / D1 {
+                    let t = t - 1.5 / D1;
+                    N1 * t * t + 0.75
+                } else if t < 2.5 / D1 {
+                    let t = t - 2.25 / D1;
+                    N1 * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / D1;
+                    N1 * t * t + 0.984375
+                }
+            }
+            EasingCurve::InOutBounce => {
+                if t < 0.5 {
+                    (1. - EasingCurve::OutBounce.apply(1. - 2. * t)) / 2.
+                } else {
+                    (1. + EasingCurve::OutBounce.apply(2. * t - 1.)) / 2.
+                }
+            }
+        }
+    }
+}
+
+/// Applies an easing curve to a number from 0 to 1.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct EaseExpression {
+    pub curve: EasingCurve,
+    pub number: Box<AnyExpression>
+}
+
+impl EaseExpression {
+    #[allow(unused)]
+    pub fn new(curve: EasingCurve, number: AnyExpression) -> AnyExpression {
+        AnyExpression::Ease(EaseExpression {
+            curve,
+            number: Box::new(number)
+        })
+    }
+}
+
+impl Expression for EaseExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.curve.apply(self.number.compute(context));
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number]
+    }
+}
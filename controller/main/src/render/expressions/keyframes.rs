@@ -0,0 +1,170 @@
+use reflection::Reflect;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{AnyExpression, EasingCurve, Expression};
+
+/// What a keyframes expression does outside the time range of its keyframes.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum KeyframeMode {
+    /// Holds the first value before the first keyframe and the last value after the last one.
+    Hold,
+    /// Repeats the keyframes, jumping back to the first keyframe after the last one.
+    Loop,
+    /// Plays the keyframes forward, then backward, and repeats.
+    PingPong
+}
+
+impl KeyframeMode {
+    pub const ALL: [KeyframeMode; 3] = [KeyframeMode::Hold, KeyframeMode::Loop, KeyframeMode::PingPong];
+
+    /// The name of the mode's function in the text form of expressions.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            KeyframeMode::Hold => "keyframes",
+            KeyframeMode::Loop => "keyframes_loop",
+            KeyframeMode::PingPong => "keyframes_ping_pong"
+        }
+    }
+}
+
+/// A value at a point in time.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Keyframe {
+    pub time: f64,
+    pub value: f64,
+    /// The easing curve used to reach this keyframe's value from the previous one. Defaults to linear.
+    pub easing: Option<EasingCurve>
+}
+
+/// Interpolates between keyframes, using the input as the time; usually, the input is `time`.
+/// Keyframes are sorted by time when they're loaded.
+/// Written as `keyframes(input, time, value, time, value, ...)`, or `keyframes_loop` and `keyframes_ping_pong`
+/// for the other modes, in the text form of expressions. A keyframe's easing is written by wrapping its value,
+/// like `keyframes(time, 0, 0, 5, ease_in_quad(255))`.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct KeyframesExpression {
+    pub input: Box<AnyExpression>,
+    #[serde(deserialize_with = "deserialize_keyframes")]
+    pub keyframes: Vec<Keyframe>,
+    pub mode: KeyframeMode
+}
+
+/// Sorts keyframes by time, since computing the expression relies on them being in order.
+fn deserialize_keyframes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Keyframe>, D::Error> {
+    let mut keyframes = Vec::<Keyframe>::deserialize(deserializer)?;
+    keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+    Ok(keyframes)
+}
+
+impl KeyframesExpression {
+    #[allow(unused)]
+    pub fn new(input: AnyExpression, keyframes: Vec<Keyframe>, mode: KeyframeMode) -> AnyExpression {
+        AnyExpression::Keyframes(KeyframesExpression {
+            input: Box::new(input),
+            keyframes,
+            mode
+        })
+    }
+}
+
+impl Expression for KeyframesExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        let (Some(first), Some(last)) = (self.keyframes.first(), self.keyframes.last()) else {
+            return 0.;
+        };
+
+        let duration = last.time - first.time;
+        let input = self.input.compute(context);
+        let time = if duration <= 0. {
+            input
+        } else {
+            match self.mode {
+                KeyframeMode::Hold => input,
+                KeyframeMode::Loop => first.time + (input - first.time).rem_euclid(duration),
+                KeyframeMode::PingPong => {
+                    let elapsed = (input - first.time).rem_euclid(duration * 2.);
+                    first.time + if elapsed > duration { duration * 2. - elapsed } else { elapsed }
+                }
+            }
+        };
+
+        // The first keyframe after the time
+        let next = self.keyframes.partition_point(|keyframe| keyframe.time <= time);
+        if next == 0 {
+            return first.value;
+        }
+        if next == self.keyframes.len() {
+            return last.value;
+        }
+
+        let start = &self.keyframes[next - 1];
+        let end = &self.keyframes[next];
+        let t = (time - start.time) / (end.time - start.time);
+        let t = end.easing.unwrap_or(EasingCurve::Linear).apply(t);
+        return start.value + (end.value - start.value) * t;
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.input]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::{effects::RenderContext, expressions::LiteralExpression};
+    use super::*;
+
+    fn keyframe(time: f64, value: f64) -> Keyframe {
+        Keyframe { time, value, easing: None }
+    }
+
+    /// Computes keyframes at 1, 2 and 3 seconds with values 10, 20 and 0 at every input.
+    fn compute(mode: KeyframeMode, inputs: &[f64]) -> Vec<f64> {
+        let info = crate::RenderInfo::new(&crate::spatial_map());
+        let context = RenderContext { delta: std::time::Duration::ZERO, time: 0., pixels: 1 }.expression_context(&info);
+        inputs.iter().map(|&input| {
+            let keyframes = vec![keyframe(1., 10.), keyframe(2., 20.), keyframe(3., 0.)];
+            KeyframesExpression::new(LiteralExpression::new(input), keyframes, mode).compute(&context)
+        }).collect()
+    }
+
+    #[test]
+    fn hold_keeps_the_first_and_last_values() {
+        assert_eq!(
+            compute(KeyframeMode::Hold, &[0., 1., 1.5, 2., 2.5, 3., 4.]),
+            vec![10., 10., 15., 20., 10., 0., 0.]
+        );
+    }
+
+    #[test]
+    fn loop_jumps_back_to_the_first_keyframe() {
+        assert_eq!(
+            compute(KeyframeMode::Loop, &[-1., 0.5, 1., 2.5, 3., 3.5, 4.]),
+            vec![10., 10., 10., 10., 10., 15., 20.]
+        );
+    }
+
+    #[test]
+    fn ping_pong_plays_backward_after_the_last_keyframe() {
+        assert_eq!(
+            compute(KeyframeMode::PingPong, &[0., 1., 2.5, 3., 3.5, 4., 4.5, 5.]),
+            vec![20., 10., 10., 0., 10., 20., 15., 10.]
+        );
+    }
+
+    #[test]
+    fn sorts_keyframes_when_deserialized() {
+        let expression: KeyframesExpression = serde_json::from_str(r#"{
+            "input": { "type": "Number", "value": 0 },
+            "keyframes": [
+                { "time": 3, "value": 0, "easing": null },
+                { "time": 1, "value": 10, "easing": null },
+                { "time": 2, "value": 20, "easing": null }
+            ],
+            "mode": { "type": "Hold" }
+        }"#).unwrap();
+        let times = expression.keyframes.iter().map(|keyframe| keyframe.time).collect::<Vec<_>>();
+        assert_eq!(times, vec![1., 2., 3.]);
+    }
+}
@@ -0,0 +1,220 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use super::{AnyExpression, Expression};
+
+/// The sine of a number in radians.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct SinExpression {
+    pub number: Box<AnyExpression>
+}
+
+impl SinExpression {
+    #[allow(unused)]
+    pub fn new(number: AnyExpression) -> AnyExpression {
+        AnyExpression::Sin(SinExpression {
+            number: Box::new(number)
+        })
+    }
+}
+
+impl Expression for SinExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.number.compute(context).sin();
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number]
+    }
+}
+
+/// The cosine of a number in radians.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct CosExpression {
+    pub number: Box<AnyExpression>
+}
+
+impl CosExpression {
+    #[allow(unused)]
+    pub fn new(number: AnyExpression) -> AnyExpression {
+        AnyExpression::Cos(CosExpression {
+            number: Box::new(number)
+        })
+    }
+}
+
+impl Expression for CosExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.number.compute(context).cos();
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number]
+    }
+}
+
+/// The absolute value of a number.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct AbsExpression {
+    pub number: Box<AnyExpression>
+}
+
+impl AbsExpression {
+    #[allow(unused)]
+    pub fn new(number: AnyExpression) -> AnyExpression {
+        AnyExpression::Abs(AbsExpression {
+            number: Box::new(number)
+        })
+    }
+}
+
+impl Expression for AbsExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.number.compute(context).abs();
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number]
+    }
+}
+
+/// The smaller of two numbers.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct MinExpression {
+    pub left: Box<AnyExpression>,
+    pub right: Box<AnyExpression>
+}
+
+impl MinExpression {
+    #[allow(unused)]
+    pub fn new(left: AnyExpression, right: AnyExpression) -> AnyExpression {
+        AnyExpression::Min(MinExpression {
+            left: Box::new(left),
+            right: Box::new(right)
+        })
+    }
+}
+
+impl Expression for MinExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.left.compute(context).min(self.right.compute(context));
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.left, &mut *self.right]
+    }
+}
+
+/// The larger of two numbers.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct MaxExpression {
+    pub left: Box<AnyExpression>,
+    pub right: Box<AnyExpression>
+}
+
+impl MaxExpression {
+    #[allow(unused)]
+    pub fn new(left: AnyExpression, right: AnyExpression) -> AnyExpression {
+        AnyExpression::Max(MaxExpression {
+            left: Box::new(left),
+            right: Box::new(right)
+        })
+    }
+}
+
+impl Expression for MaxExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.left.compute(context).max(self.right.compute(context));
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.left, &mut *self.right]
+    }
+}
+
+/// The remainder of dividing two numbers. The result is never negative, so it's useful for looping over time.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ModuloExpression {
+    pub left: Box<AnyExpression>,
+    pub right: Box<AnyExpression>
+}
+
+impl ModuloExpression {
+    #[allow(unused)]
+    pub fn new(left: AnyExpression, right: AnyExpression) -> AnyExpression {
+        AnyExpression::Modulo(ModuloExpression {
+            left: Box::new(left),
+            right: Box::new(right)
+        })
+    }
+}
+
+impl Expression for ModuloExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.left.compute(context).rem_euclid(self.right.compute(context));
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.left, &mut *self.right]
+    }
+}
+
+/// A number raised to a power.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct PowerExpression {
+    pub base: Box<AnyExpression>,
+    pub exponent: Box<AnyExpression>
+}
+
+impl PowerExpression {
+    #[allow(unused)]
+    pub fn new(base: AnyExpression, exponent: AnyExpression) -> AnyExpression {
+        AnyExpression::Power(PowerExpression {
+            base: Box::new(base),
+            exponent: Box::new(exponent)
+        })
+    }
+}
+
+impl Expression for PowerExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return self.base.compute(context).powf(self.exponent.compute(context));
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.base, &mut *self.exponent]
+    }
+}
+
+/// Limits a number to a range.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ClampExpression {
+    pub number: Box<AnyExpression>,
+    pub min: Box<AnyExpression>,
+    pub max: Box<AnyExpression>
+}
+
+impl ClampExpression {
+    #[allow(unused)]
+    pub fn new(number: AnyExpression, min: AnyExpression, max: AnyExpression) -> AnyExpression {
+        AnyExpression::Clamp(ClampExpression {
+            number: Box::new(number),
+            min: Box::new(min),
+            max: Box::new(max)
+        })
+    }
+}
+
+impl Expression for ClampExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        let number = self.number.compute(context);
+        let min = self.min.compute(context);
+        let max = self.max.compute(context);
+        // f64::clamp panics if min > max, which is easy to do by accident in an expression
+        return number.max(min).min(max);
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number, &mut *self.min, &mut *self.max]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::render::random::{hash_to_unit, perlin_noise_1d, perlin_noise_2d, value_noise_1d, value_noise_2d, SeededRng};
+
+use super::{AnyExpression, Expression};
+
+/// A random number from 0 to 1. Without an input, a new number is generated every time it's computed.
+/// With an input, the number only changes when the input crosses a whole number, so `random(1, pixel)`
+/// gives every pixel its own random value that stays the same across frames.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct RandomExpression {
+    pub seed: u32,
+    pub input: Option<Box<AnyExpression>>,
+
+    #[serde(skip)]
+    rng: Option<SeededRng>
+}
+
+impl RandomExpression {
+    #[allow(unused)]
+    pub fn new(seed: u32, input: Option<AnyExpression>) -> AnyExpression {
+        AnyExpression::Random(RandomExpression {
+            seed,
+            input: input.map(Box::new),
+            rng: None
+        })
+    }
+}
+
+impl Expression for RandomExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        if let Some(input) = &mut self.input {
+            return hash_to_unit(self.seed as u64, input.compute(context).floor() as i64);
+        }
+        let seed = self.seed as u64;
+        return self.rng.get_or_insert_with(|| SeededRng::new(seed)).next_f64();
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        self.input.iter_mut().map(|input| &mut **input).collect()
+    }
+
+    /// Without an input, every computation gives a new number, so it can't be folded.
+    fn depends_on_context(&self) -> bool {
+        self.input.is_none()
+    }
+}
+
+/// The kind of smooth noise a noise expression generates.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum NoiseKind {
+    /// Smoothly interpolated random values. Cheap, but slightly blocky.
+    Value,
+    /// Gradient noise, which looks more natural.
+    Perlin
+}
+
+impl NoiseKind {
+    /// The name of the noise's function in the text form of expressions.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            NoiseKind::Value => "noise",
+            NoiseKind::Perlin => "perlin"
+        }
+    }
+}
+
+/// Smooth noise from 0 to 1 with a feature size of 1 unit. With only `x`, the noise is 1D (for example,
+/// over time); with `y` as well, it's 2D (for example, over time and the pixel's position).
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct NoiseExpression {
+    pub kind: NoiseKind,
+    pub seed: u32,
+    pub x: Box<AnyExpression>,
+    pub y: Option<Box<AnyExpression>>
+}
+
+impl NoiseExpression {
+    #[allow(unused)]
+    pub fn new(kind: NoiseKind, seed: u32, x: AnyExpression, y: Option<AnyExpression>) -> AnyExpression {
+        AnyExpression::Noise(NoiseExpression {
+            kind,
+            seed,
+            x: Box::new(x),
+            y: y.map(Box::new)
+        })
+    }
+}
+
+impl Expression for NoiseExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        let seed = self.seed as u64;
+        let x = self.x.compute(context);
+        let y = self.y.as_mut().map(|y| y.compute(context));
+
+        return match (self.kind, y) {
+            (NoiseKind::Value, None) => value_noise_1d(seed, x),
+            (NoiseKind::Value, Some(y)) => value_noise_2d(seed, x, y),
+            (NoiseKind::Perlin, None) => perlin_noise_1d(seed, x),
+            (NoiseKind::Perlin, Some(y)) => perlin_noise_2d(seed, x, y)
+        };
+    }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        let mut inputs = vec![&mut *self.x];
+        inputs.extend(self.y.as_deref_mut());
+        inputs
+    }
+}
@@ -21,6 +21,10 @@ impl Expression for RoundExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return self.number.compute(context).round();
     }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number]
+    }
 }
 
 #[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
@@ -41,6 +45,10 @@ impl Expression for CeilExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return self.number.compute(context).ceil();
     }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number]
+    }
 }
 
 
@@ -62,4 +70,8 @@ impl Expression for FloorExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return self.number.compute(context).floor();
     }
+
+    fn inputs(&mut self) -> Vec<&mut AnyExpression> {
+        vec![&mut *self.number]
+    }
 }
\ No newline at end of file
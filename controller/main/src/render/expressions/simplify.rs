@@ -0,0 +1,66 @@
+use crate::render::{audio::AudioData, variables::Variables};
+
+use super::{AnyColorExpression, AnyExpression, ColorExpression, ConstantColorExpression, Expression, ExpressionContext, LiteralExpression};
+
+/// Runs a computation with a placeholder context.
+/// The context isn't used by expressions that don't depend on it, so this is only valid for those.
+fn with_constant_context<R>(compute: impl FnOnce(&ExpressionContext) -> R) -> R {
+    let variables = Variables::default();
+    let audio = AudioData::default();
+    let context = ExpressionContext {
+        current_time: 0.,
+        frame: 0,
+        pixel: 0,
+        position: 0.,
+        x: 0.,
+        y: 0.,
+        variables: &variables,
+        audio: &audio
+    };
+    compute(&context)
+}
+
+impl AnyExpression {
+    /// Replaces every part of the expression that doesn't depend on the context, like `2 * pi`, with its value
+    /// so it isn't recomputed for every pixel of every frame. Selects with a constant condition are replaced by
+    /// the chosen expression. This runs when an effect is loaded for rendering.
+    pub fn fold_constants(&mut self) {
+        let mut constant = !self.depends_on_context();
+        for input in self.inputs() {
+            input.fold_constants();
+            constant &= matches!(input, AnyExpression::Number(_));
+        }
+
+        if let AnyExpression::Select(select) = self {
+            if let AnyExpression::Number(condition) = &*select.condition {
+                let chosen = if condition.value > 0. { &mut select.if_true } else { &mut select.if_false };
+                *self = std::mem::replace(&mut **chosen, LiteralExpression::new(0.));
+                return;
+            }
+        }
+
+        if constant && !matches!(self, AnyExpression::Number(_)) {
+            *self = LiteralExpression::new(with_constant_context(|context| self.compute(context)));
+        }
+    }
+}
+
+impl AnyColorExpression {
+    /// Folds the constants in every input, and replaces the color expression with a constant color
+    /// if all of its inputs are constant.
+    pub fn fold_constants(&mut self) {
+        let mut constant = true;
+        for input in self.inputs() {
+            input.fold_constants();
+            constant &= matches!(input, AnyExpression::Number(_));
+        }
+        for input in self.color_inputs() {
+            input.fold_constants();
+            constant &= matches!(input, AnyColorExpression::Constant(_));
+        }
+
+        if constant && !matches!(self, AnyColorExpression::Constant(_)) {
+            *self = ConstantColorExpression::new(with_constant_context(|context| self.compute(context)));
+        }
+    }
+}
@@ -0,0 +1,585 @@
+use std::fmt::{self, Display};
+
+use super::{AbsExpression, AddExpression, AnyExpression, AudioExpression, AudioValue, CeilExpression, ClampExpression, CosExpression, CurrentTimeExpression, DivideExpression, EaseExpression, EasingCurve, FloorExpression, FrameCountExpression, Keyframe, KeyframeMode, KeyframesExpression, LerpExpression, LiteralExpression, MaxExpression, MinExpression, ModuloExpression, MultiplyExpression, NoiseExpression, NoiseKind, PixelIndexExpression, PixelPositionExpression, PixelXExpression, PixelYExpression, PowerExpression, RandomExpression, RoundExpression, SelectExpression, SinExpression, SmoothstepExpression, SubtractExpression, VariableExpression};
+
+// Expressions can be written as text, like `time * 84 + round(time / 2)`.
+// Operators follow the usual precedence rules: *, / and % bind tighter than + and -, and
+// operators of the same precedence are evaluated left to right.
+// Variables set at runtime are written with a `$`, like `time * $speed`.
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    /// A runtime variable, like `$speed`.
+    Variable(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    OpenParen,
+    CloseParen,
+    Comma
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(value) => write!(f, "{}", value),
+            Token::Identifier(name) => write!(f, "{}", name),
+            Token::Variable(name) => write!(f, "${}", name),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Star => write!(f, "*"),
+            Token::Slash => write!(f, "/"),
+            Token::Percent => write!(f, "%"),
+            Token::OpenParen => write!(f, "("),
+            Token::CloseParen => write!(f, ")"),
+            Token::Comma => write!(f, ",")
+        }
+    }
+}
+
+/// Splits the text into tokens, along with the character position each token starts at.
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, String> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let token = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            ',' => Token::Comma,
+            c if c.is_ascii_digit() || c == '.' => {
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                // Exponents, like 1.5e-3
+                if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                    let mut end = i + 1;
+                    if end < chars.len() && (chars[end] == '+' || chars[end] == '-') {
+                        end += 1;
+                    }
+                    if end < chars.len() && chars[end].is_ascii_digit() {
+                        i = end;
+                        while i < chars.len() && chars[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+
+                let number = chars[start..i].iter().collect::<String>();
+                let value = number.parse::<f64>().map_err(|_| format!("Invalid number {} at position {}", number, start))?;
+                tokens.push((Token::Number(value), start));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push((Token::Identifier(chars[start..i].iter().collect()), start));
+                continue;
+            }
+            '$' => {
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                if i == start + 1 {
+                    return Err(format!("Expected a variable name after '$' at position {}", start));
+                }
+                tokens.push((Token::Variable(chars[start + 1..i].iter().collect()), start));
+                continue;
+            }
+            c => return Err(format!("Unexpected character '{}' at position {}", c, start))
+        };
+
+        tokens.push((token, start));
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+/// How deeply parentheses, function calls and negations can be nested.
+/// The parser is recursive, so this keeps deeply nested text from overflowing the stack.
+static MAX_DEPTH: usize = 64;
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    /// The length of the text, used to report errors at the end of the input.
+    length: usize,
+    /// How many nested parentheses, function calls and negations are being parsed.
+    depth: usize
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.position += 1;
+        token
+    }
+
+    /// The character position of the next token, for error messages.
+    fn location(&self) -> usize {
+        self.tokens.get(self.position).map(|(_, position)| *position).unwrap_or(self.length)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        let location = self.location();
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("Expected '{}' at position {}, but found '{}'", expected, location, token)),
+            None => Err(format!("Expected '{}' at position {}, but the expression ended", expected, location))
+        }
+    }
+
+    /// Parses something nested inside another expression, failing if it's nested too deeply.
+    fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Result<AnyExpression, String>) -> Result<AnyExpression, String> {
+        if self.depth >= MAX_DEPTH {
+            return Err("Expression is nested too deeply".to_string());
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// additive := multiplicative (('+' | '-') multiplicative)*
+    fn additive(&mut self) -> Result<AnyExpression, String> {
+        let mut left = self.multiplicative()?;
+        loop {
+            match self.peek() {
+                Some(Token::Plus) => {
+                    self.next();
+                    left = AddExpression::new(left, self.multiplicative()?);
+                }
+                Some(Token::Minus) => {
+                    self.next();
+                    left = SubtractExpression::new(left, self.multiplicative()?);
+                }
+                _ => return Ok(left)
+            }
+        }
+    }
+
+    /// multiplicative := unary (('*' | '/' | '%') unary)*
+    fn multiplicative(&mut self) -> Result<AnyExpression, String> {
+        let mut left = self.unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::Star) => {
+                    self.next();
+                    left = MultiplyExpression::new(left, self.unary()?);
+                }
+                Some(Token::Slash) => {
+                    self.next();
+                    left = DivideExpression::new(left, self.unary()?);
+                }
+                Some(Token::Percent) => {
+                    self.next();
+                    left = ModuloExpression::new(left, self.unary()?);
+                }
+                _ => return Ok(left)
+            }
+        }
+    }
+
+    /// unary := '-' unary | primary
+    fn unary(&mut self) -> Result<AnyExpression, String> {
+        if self.peek() != Some(&Token::Minus) {
+            return self.primary();
+        }
+
+        self.next();
+        Ok(match self.nested(Self::unary)? {
+            AnyExpression::Number(literal) => LiteralExpression::new(-literal.value),
+            expression => SubtractExpression::new(LiteralExpression::new(0.), expression)
+        })
+    }
+
+    /// primary := number | variable | function '(' arguments ')' | '(' additive ')'
+    fn primary(&mut self) -> Result<AnyExpression, String> {
+        let location = self.location();
+        match self.next() {
+            Some(Token::Number(value)) => Ok(LiteralExpression::new(value)),
+            Some(Token::OpenParen) => self.nested(|parser| {
+                let expression = parser.additive()?;
+                parser.expect(Token::CloseParen)?;
+                Ok(expression)
+            }),
+            Some(Token::Identifier(name)) if self.peek() == Some(&Token::OpenParen) => self.nested(|parser| {
+                parser.next();
+                let mut arguments = vec![];
+                if parser.peek() != Some(&Token::CloseParen) {
+                    arguments.push(parser.additive()?);
+                    while parser.peek() == Some(&Token::Comma) {
+                        parser.next();
+                        arguments.push(parser.additive()?);
+                    }
+                }
+                parser.expect(Token::CloseParen)?;
+                function(&name, arguments).map_err(|e| format!("{} at position {}", e, location))
+            }),
+            Some(Token::Identifier(name)) => variable(&name).map_err(|e| format!("{} at position {}", e, location)),
+            Some(Token::Variable(name)) => Ok(VariableExpression::new(name)),
+            Some(token) => Err(format!("Unexpected '{}' at position {}", token, location)),
+            None => Err(format!("Expected a value at position {}, but the expression ended", location))
+        }
+    }
+}
+
+fn variable(name: &str) -> Result<AnyExpression, String> {
+    match name {
+        "time" => Ok(CurrentTimeExpression::new()),
+        "frame" => Ok(FrameCountExpression::new()),
+        "pixel" => Ok(PixelIndexExpression::new()),
+        "position" => Ok(PixelPositionExpression::new()),
+        "x" => Ok(PixelXExpression::new()),
+        "y" => Ok(PixelYExpression::new()),
+        "pi" => Ok(LiteralExpression::new(std::f64::consts::PI)),
+        // So numbers that aren't finite can be printed in a form that parses back
+        "inf" => Ok(LiteralExpression::new(f64::INFINITY)),
+        "nan" => Ok(LiteralExpression::new(f64::NAN)),
+        _ => match AudioValue::ALL.iter().find(|value| value.variable_name() == name) {
+            Some(value) => Ok(AudioExpression::new(*value)),
+            None => Err(format!("Unknown variable {}", name))
+        }
+    }
+}
+
+/// Gets a seed from a function argument, which must be a whole number so it can be stored exactly.
+fn seed(name: &str, argument: &AnyExpression) -> Result<u32, String> {
+    match argument {
+        AnyExpression::Number(literal) if literal.value.fract() == 0. && (0. ..=u32::MAX as f64).contains(&literal.value) => Ok(literal.value as u32),
+        _ => Err(format!("The seed of {} must be a whole number from 0 to {}", name, u32::MAX))
+    }
+}
+
+/// Checks that a function was called with the right number of arguments.
+fn arguments<const N: usize>(name: &str, arguments: Vec<AnyExpression>) -> Result<[AnyExpression; N], String> {
+    arguments.try_into().map_err(|arguments: Vec<_>| {
+        format!("{} takes {} argument{}, but got {}", name, N, if N == 1 { "" } else { "s" }, arguments.len())
+    })
+}
+
+fn function(name: &str, args: Vec<AnyExpression>) -> Result<AnyExpression, String> {
+    Ok(match name {
+        "round" => {
+            let [number] = arguments(name, args)?;
+            RoundExpression::new(number)
+        }
+        "floor" => {
+            let [number] = arguments(name, args)?;
+            FloorExpression::new(number)
+        }
+        "ceil" => {
+            let [number] = arguments(name, args)?;
+            CeilExpression::new(number)
+        }
+        "sin" => {
+            let [number] = arguments(name, args)?;
+            SinExpression::new(number)
+        }
+        "cos" => {
+            let [number] = arguments(name, args)?;
+            CosExpression::new(number)
+        }
+        "abs" => {
+            let [number] = arguments(name, args)?;
+            AbsExpression::new(number)
+        }
+        "min" => {
+            let [left, right] = arguments(name, args)?;
+            MinExpression::new(left, right)
+        }
+        "max" => {
+            let [left, right] = arguments(name, args)?;
+            MaxExpression::new(left, right)
+        }
+        "clamp" => {
+            let [number, min, max] = arguments(name, args)?;
+            ClampExpression::new(number, min, max)
+        }
+        "mod" => {
+            let [left, right] = arguments(name, args)?;
+            ModuloExpression::new(left, right)
+        }
+        "pow" => {
+            let [base, exponent] = arguments(name, args)?;
+            PowerExpression::new(base, exponent)
+        }
+        "lerp" => {
+            let [start, end, t] = arguments(name, args)?;
+            LerpExpression::new(start, end, t)
+        }
+        "smoothstep" => {
+            let [start, end, number] = arguments(name, args)?;
+            SmoothstepExpression::new(start, end, number)
+        }
+        "select" => {
+            let [condition, if_true, if_false] = arguments(name, args)?;
+            SelectExpression::new(condition, if_true, if_false)
+        }
+        "random" => {
+            let mut args = args.into_iter();
+            match (args.next(), args.next(), args.next()) {
+                (Some(seed_argument), input, None) => RandomExpression::new(seed(name, &seed_argument)?, input),
+                _ => return Err(format!("{} takes a seed and an optional input", name))
+            }
+        }
+        "noise" | "perlin" => {
+            let kind = if name == "noise" { NoiseKind::Value } else { NoiseKind::Perlin };
+            let mut args = args.into_iter();
+            match (args.next(), args.next(), args.next(), args.next()) {
+                (Some(seed_argument), Some(x), y, None) => NoiseExpression::new(kind, seed(name, &seed_argument)?, x, y),
+                _ => return Err(format!("{} takes a seed, x, and an optional y", name))
+            }
+        }
+        _ => {
+            if let Some(curve) = EasingCurve::ALL.iter().find(|curve| curve.function_name() == name) {
+                let [number] = arguments(name, args)?;
+                return Ok(EaseExpression::new(*curve, number));
+            }
+            match KeyframeMode::ALL.iter().find(|mode| mode.function_name() == name) {
+                Some(mode) => keyframes(name, *mode, args)?,
+                None => return Err(format!("Unknown function {}", name))
+            }
+        }
+    })
+}
+
+/// Parses the arguments of a keyframes function: the input, then a time and value for every keyframe.
+/// Times and values must be numbers, but a value can be wrapped in an easing function to ease into it.
+fn keyframes(name: &str, mode: KeyframeMode, args: Vec<AnyExpression>) -> Result<AnyExpression, String> {
+    let usage = || format!("{} takes an input, then a time and value for every keyframe", name);
+    let mut args = args.into_iter();
+    let input = args.next().ok_or_else(usage)?;
+    let args = args.collect::<Vec<_>>();
+    if args.is_empty() || args.len() % 2 != 0 {
+        return Err(usage());
+    }
+
+    let mut keyframes: Vec<Keyframe> = vec![];
+    for pair in args.chunks(2) {
+        let time = match &pair[0] {
+            AnyExpression::Number(literal) => literal.value,
+            _ => return Err(format!("The keyframe times of {} must be numbers", name))
+        };
+        let (value, easing) = match &pair[1] {
+            AnyExpression::Number(literal) => (literal.value, None),
+            AnyExpression::Ease(ease) => match &*ease.number {
+                AnyExpression::Number(literal) => (literal.value, Some(ease.curve)),
+                _ => return Err(format!("The keyframe values of {} must be numbers", name))
+            },
+            _ => return Err(format!("The keyframe values of {} must be numbers", name))
+        };
+        if keyframes.last().is_some_and(|last| last.time > time) {
+            return Err(format!("The keyframes of {} must be in order of time", name));
+        }
+        keyframes.push(Keyframe { time, value, easing });
+    }
+
+    Ok(KeyframesExpression::new(input, keyframes, mode))
+}
+
+/// Parses the text form of an expression.
+pub fn parse(text: &str) -> Result<AnyExpression, String> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        position: 0,
+        length: text.chars().count(),
+        depth: 0
+    };
+
+    let expression = parser.additive()?;
+    if let Some(token) = parser.peek() {
+        return Err(format!("Unexpected '{}' at position {}", token, parser.location()));
+    }
+    Ok(expression)
+}
+
+/// Writes a number so it parses back to the same value. Rust writes infinity and NaN as `inf` and `NaN`,
+/// so they're written with the `inf` and `nan` variables instead.
+struct Number(f64);
+
+impl Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_nan() {
+            write!(f, "nan")
+        } else if self.0.is_infinite() {
+            write!(f, "{}inf", if self.0 < 0. { "-" } else { "" })
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+/// How tightly an expression binds, so the pretty-printer only adds the parentheses it needs.
+fn precedence(expression: &AnyExpression) -> u8 {
+    match expression {
+        AnyExpression::Add(_) | AnyExpression::Subtract(_) => 1,
+        AnyExpression::Multiply(_) | AnyExpression::Divide(_) | AnyExpression::Modulo(_) => 2,
+        _ => 3
+    }
+}
+
+/// Writes an operand, wrapping it in parentheses if it binds less tightly than `min_precedence`.
+fn write_operand(f: &mut fmt::Formatter<'_>, expression: &AnyExpression, min_precedence: u8) -> fmt::Result {
+    if precedence(expression) < min_precedence {
+        write!(f, "({})", expression)
+    } else {
+        write!(f, "{}", expression)
+    }
+}
+
+fn write_binary(f: &mut fmt::Formatter<'_>, parent: &AnyExpression, left: &AnyExpression, operator: &str, right: &AnyExpression) -> fmt::Result {
+    let precedence = precedence(parent);
+    write_operand(f, left, precedence)?;
+    write!(f, " {} ", operator)?;
+    // Operators are left-associative, so the right side needs parentheses at the same precedence
+    write_operand(f, right, precedence + 1)
+}
+
+/// Pretty-prints an expression in the same text form `parse` accepts.
+impl Display for AnyExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyExpression::Number(literal) => write!(f, "{}", Number(literal.value)),
+            AnyExpression::CurrentTime(_) => write!(f, "time"),
+            AnyExpression::FrameCount(_) => write!(f, "frame"),
+            AnyExpression::PixelIndex(_) => write!(f, "pixel"),
+            AnyExpression::PixelPosition(_) => write!(f, "position"),
+            AnyExpression::PixelX(_) => write!(f, "x"),
+            AnyExpression::PixelY(_) => write!(f, "y"),
+            AnyExpression::Variable(e) => write!(f, "${}", e.name),
+            AnyExpression::Audio(e) => write!(f, "{}", e.value.variable_name()),
+            AnyExpression::Add(e) => write_binary(f, self, &e.left, "+", &e.right),
+            AnyExpression::Subtract(e) => write_binary(f, self, &e.left, "-", &e.right),
+            AnyExpression::Multiply(e) => write_binary(f, self, &e.left, "*", &e.right),
+            AnyExpression::Divide(e) => write_binary(f, self, &e.left, "/", &e.right),
+            AnyExpression::Round(e) => write!(f, "round({})", e.number),
+            AnyExpression::Floor(e) => write!(f, "floor({})", e.number),
+            AnyExpression::Ceil(e) => write!(f, "ceil({})", e.number),
+            AnyExpression::Sin(e) => write!(f, "sin({})", e.number),
+            AnyExpression::Cos(e) => write!(f, "cos({})", e.number),
+            AnyExpression::Abs(e) => write!(f, "abs({})", e.number),
+            AnyExpression::Min(e) => write!(f, "min({}, {})", e.left, e.right),
+            AnyExpression::Max(e) => write!(f, "max({}, {})", e.left, e.right),
+            AnyExpression::Clamp(e) => write!(f, "clamp({}, {}, {})", e.number, e.min, e.max),
+            AnyExpression::Modulo(e) => write_binary(f, self, &e.left, "%", &e.right),
+            AnyExpression::Power(e) => write!(f, "pow({}, {})", e.base, e.exponent),
+            AnyExpression::Lerp(e) => write!(f, "lerp({}, {}, {})", e.start, e.end, e.t),
+            AnyExpression::Smoothstep(e) => write!(f, "smoothstep({}, {}, {})", e.start, e.end, e.number),
+            AnyExpression::Ease(e) => write!(f, "{}({})", e.curve.function_name(), e.number),
+            AnyExpression::Keyframes(e) => {
+                write!(f, "{}({}", e.mode.function_name(), e.input)?;
+                for keyframe in &e.keyframes {
+                    match keyframe.easing {
+                        Some(curve) if curve != EasingCurve::Linear => write!(f, ", {}, {}({})", Number(keyframe.time), curve.function_name(), Number(keyframe.value))?,
+                        _ => write!(f, ", {}, {}", Number(keyframe.time), Number(keyframe.value))?
+                    }
+                }
+                write!(f, ")")
+            }
+            AnyExpression::Select(e) => write!(f, "select({}, {}, {})", e.condition, e.if_true, e.if_false),
+            AnyExpression::Random(e) => match &e.input {
+                Some(input) => write!(f, "random({}, {})", e.seed, input),
+                None => write!(f, "random({})", e.seed)
+            },
+            AnyExpression::Noise(e) => match &e.y {
+                Some(y) => write!(f, "{}({}, {}, {})", e.kind.function_name(), e.seed, e.x, y),
+                None => write!(f, "{}({}, {})", e.kind.function_name(), e.seed, e.x)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn literal(value: f64) -> AnyExpression {
+        LiteralExpression::new(value)
+    }
+
+    /// Expressions don't implement `PartialEq`, so they're compared by their debug output.
+    /// This also treats NaN literals as equal, unlike comparing the numbers would.
+    fn assert_parses(text: &str, expected: AnyExpression) {
+        assert_eq!(format!("{:?}", parse(text).unwrap()), format!("{:?}", expected), "parsing {}", text);
+    }
+
+    #[test]
+    fn operators_of_the_same_precedence_are_left_associative() {
+        assert_parses("1-2-3", SubtractExpression::new(SubtractExpression::new(literal(1.), literal(2.)), literal(3.)));
+        assert_parses("8/4/2", DivideExpression::new(DivideExpression::new(literal(8.), literal(4.)), literal(2.)));
+        assert_parses("1+2*3", AddExpression::new(literal(1.), MultiplyExpression::new(literal(2.), literal(3.))));
+        assert_parses("(1+2)*3", MultiplyExpression::new(AddExpression::new(literal(1.), literal(2.)), literal(3.)));
+    }
+
+    #[test]
+    fn negation_binds_tighter_than_multiplication() {
+        assert_parses("-2*3", MultiplyExpression::new(literal(-2.), literal(3.)));
+        assert_parses("2*-x", MultiplyExpression::new(literal(2.), SubtractExpression::new(literal(0.), PixelXExpression::new())));
+        assert_parses("--2", literal(2.));
+    }
+
+    #[test]
+    fn errors_report_positions() {
+        assert_eq!(parse("1 + ").unwrap_err(), "Expected a value at position 4, but the expression ended");
+        assert_eq!(parse("1 + * 2").unwrap_err(), "Unexpected '*' at position 4");
+        assert_eq!(parse("(1 + 2").unwrap_err(), "Expected ')' at position 6, but the expression ended");
+        assert_eq!(parse("1 2").unwrap_err(), "Unexpected '2' at position 2");
+        assert_eq!(parse("2 * foo").unwrap_err(), "Unknown variable foo at position 4");
+        assert_eq!(parse("1 # 2").unwrap_err(), "Unexpected character '#' at position 2");
+    }
+
+    #[test]
+    fn rejects_deeply_nested_expressions() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(parse(&nested(MAX_DEPTH + 1)).unwrap_err(), "Expression is nested too deeply");
+        assert_eq!(parse(&nested(100_000)).unwrap_err(), "Expression is nested too deeply");
+        assert_eq!(parse(&format!("{}1{}", "sin(".repeat(100_000), ")".repeat(100_000))).unwrap_err(), "Expression is nested too deeply");
+        assert_eq!(parse(&format!("{}x", "-".repeat(100_000))).unwrap_err(), "Expression is nested too deeply");
+    }
+
+    #[test]
+    fn displayed_expressions_parse_to_the_same_expression() {
+        let texts = [
+            "time * 84 + round(time / 2)",
+            "1 - (2 - 3)",
+            "(1 + 2) * -x",
+            "8 / (4 * 2) % 3",
+            "clamp(sin(time), -0.5, pow(2, $speed))",
+            "select(level, lerp(0, 1, position), smoothstep(0, 1, y))",
+            "random(3, pixel) + perlin(2, x, y) + noise(1, frame)",
+            "ease_in_quad(time % 1)",
+            "keyframes_ping_pong(time, 0, 1, 2, ease_out_cubic(0.5))",
+            "inf - -inf + nan",
+            "keyframes(time, 0, nan, 1, inf)"
+        ];
+        for text in texts {
+            let expression = parse(text).unwrap();
+            assert_parses(&expression.to_string(), expression);
+        }
+    }
+}
@@ -38,4 +38,118 @@ impl Expression for CurrentTimeExpression {
     fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
         return context.current_time;
     }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
+}
+
+/// Gets the number of frames rendered so far.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct FrameCountExpression {
+}
+
+impl FrameCountExpression {
+    #[allow(unused)]
+    pub fn new() -> AnyExpression {
+        AnyExpression::FrameCount(FrameCountExpression {})
+    }
+}
+
+impl Expression for FrameCountExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return context.frame as f64;
+    }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
+}
+
+/// Gets the index of the pixel being computed.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct PixelIndexExpression {
+}
+
+impl PixelIndexExpression {
+    #[allow(unused)]
+    pub fn new() -> AnyExpression {
+        AnyExpression::PixelIndex(PixelIndexExpression {})
+    }
+}
+
+impl Expression for PixelIndexExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return context.pixel as f64;
+    }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
+}
+
+/// Gets the position of the pixel being computed along the strip, from 0 to 1.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct PixelPositionExpression {
+}
+
+impl PixelPositionExpression {
+    #[allow(unused)]
+    pub fn new() -> AnyExpression {
+        AnyExpression::PixelPosition(PixelPositionExpression {})
+    }
+}
+
+impl Expression for PixelPositionExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return context.position;
+    }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
+}
+
+/// Gets the x coordinate of the pixel being computed in the room, in meters.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct PixelXExpression {
+}
+
+impl PixelXExpression {
+    #[allow(unused)]
+    pub fn new() -> AnyExpression {
+        AnyExpression::PixelX(PixelXExpression {})
+    }
+}
+
+impl Expression for PixelXExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return context.x;
+    }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
+}
+
+/// Gets the y coordinate of the pixel being computed in the room, in meters.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct PixelYExpression {
+}
+
+impl PixelYExpression {
+    #[allow(unused)]
+    pub fn new() -> AnyExpression {
+        AnyExpression::PixelY(PixelYExpression {})
+    }
+}
+
+impl Expression for PixelYExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return context.y;
+    }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
 }
\ No newline at end of file
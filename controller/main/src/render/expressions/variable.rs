@@ -0,0 +1,28 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
+use super::{AnyExpression, Expression};
+
+/// Gets the value of a variable set at runtime, or 0 if it isn't set.
+/// Written as `$name` in the text form of expressions.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct VariableExpression {
+    pub name: String
+}
+
+impl VariableExpression {
+    #[allow(unused)]
+    pub fn new(name: String) -> AnyExpression {
+        AnyExpression::Variable(VariableExpression { name })
+    }
+}
+
+impl Expression for VariableExpression {
+    fn compute<'a>(&mut self, context: &'a super::ExpressionContext) -> f64 {
+        return context.variables.get(&self.name).unwrap_or(0.);
+    }
+
+    fn depends_on_context(&self) -> bool {
+        true
+    }
+}
@@ -45,6 +45,37 @@ impl PixelColor {
         (hsl.h, hsl.s, hsl.l)
     }
 
+    /// Approximates the color of a black body at a temperature in Kelvin, from 1000K to 40000K.
+    /// Lower temperatures are warmer. The approximation is from
+    /// https://tannerhelland.com/2012/09/18/convert-temperature-rgb-algorithm-code.html
+    pub fn from_kelvin(kelvin: f64, alpha: f64) -> PixelColor {
+        let temperature = kelvin.clamp(1000., 40000.) / 100.;
+        let r = if temperature <= 66. {
+            255.
+        } else {
+            329.698727446 * (temperature - 60.).powf(-0.1332047592)
+        };
+        let g = if temperature <= 66. {
+            99.4708025861 * temperature.ln() - 161.1195681661
+        } else {
+            288.1221695283 * (temperature - 60.).powf(-0.0755148492)
+        };
+        let b = if temperature >= 66. {
+            255.
+        } else if temperature <= 19. {
+            0.
+        } else {
+            138.5177312231 * (temperature - 10.).ln() - 305.0447927307
+        };
+
+        PixelColor {
+            r: r.clamp(0., 255.) as u8,
+            g: g.clamp(0., 255.) as u8,
+            b: b.clamp(0., 255.) as u8,
+            alpha
+        }
+    }
+
     pub fn with_alpha(&self, alpha: f64) -> PixelColor {
         PixelColor {
             r: self.r,
@@ -134,6 +165,11 @@ impl Frame {
     pub fn get_pixel_mut(&mut self, index: u32) -> Option<&mut PixelColor> {
         self.pixel_data.get_mut(index as usize)
     }
+
+    /// The number of pixels in the frame.
+    pub fn pixel_count(&self) -> u32 {
+        self.pixel_data.len() as u32
+    }
 }
 
 
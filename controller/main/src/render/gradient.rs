@@ -0,0 +1,337 @@
+use reflection::Reflect;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{expressions::{AnyColorExpression, ColorExpression, ExpressionContext}, frame::PixelColor};
+
+/// How colors are blended between the stops of a gradient.
+#[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum GradientInterpolation {
+    /// Blends each channel separately. Cheap, but blends between very different colors can look muddy.
+    RGB,
+    /// Blends around the color wheel the short way, so red to blue goes through magenta.
+    HSL,
+    /// Blends in a perceptual color space, so the brightness changes evenly.
+    OKLab
+}
+
+/// A color at a position in a gradient.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+pub struct ColorStop {
+    /// The position of the stop, from 0 to 1.
+    pub position: f64,
+    pub color: AnyColorExpression
+}
+
+/// A gradient of color stops that effects and color expressions can sample by position.
+/// A plain list of colors, like `[{ "r": 255, "g": 0, "b": 0, "alpha": 1 }, ...]`, is deserialized as evenly
+/// spaced stops that are blended in RGB without wrapping.
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug)]
+#[serde(remote = "Self")]
+pub struct Gradient {
+    pub stops: Vec<ColorStop>,
+    pub interpolation: GradientInterpolation,
+    /// Whether positions outside 0 to 1 repeat the gradient instead of being clamped, with the last stop blending back into the first.
+    pub wrap: bool,
+
+    /// The computed gradient, kept while every stop is a constant color so sampling doesn't compute it again.
+    #[serde(skip)]
+    computed: Option<ComputedGradient>
+}
+
+impl Serialize for Gradient {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Gradient::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Gradient {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(colors) => {
+                let colors = colors.into_iter()
+                    .map(<AnyColorExpression as Deserialize>::deserialize)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(D::Error::custom)?;
+                Ok(Gradient::evenly_spaced(colors, GradientInterpolation::RGB, false))
+            }
+            value => Gradient::deserialize(value).map_err(D::Error::custom)
+        }
+    }
+}
+
+impl Gradient {
+    /// Creates a gradient with the colors evenly spaced. Wrapping gradients leave a gap after the last color
+    /// for it to blend back into the first; otherwise, the colors span from 0 to 1.
+    pub fn evenly_spaced(colors: Vec<impl Into<AnyColorExpression>>, interpolation: GradientInterpolation, wrap: bool) -> Gradient {
+        let spacing = if wrap { colors.len() } else { colors.len().saturating_sub(1) }.max(1) as f64;
+        Gradient {
+            stops: colors.into_iter().enumerate().map(|(i, color)| ColorStop {
+                position: i as f64 / spacing,
+                color: color.into()
+            }).collect(),
+            interpolation,
+            wrap,
+            computed: None
+        }
+    }
+
+    /// Computes the colors of the stops so the gradient can be sampled.
+    /// Effects should do this once per frame rather than for every pixel.
+    pub fn compute(&mut self, context: &ExpressionContext) -> ComputedGradient {
+        let mut stops = self.stops.iter_mut()
+            .map(|stop| (stop.position, stop.color.compute(context)))
+            .collect::<Vec<_>>();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        ComputedGradient {
+            stops,
+            interpolation: self.interpolation,
+            wrap: self.wrap
+        }
+    }
+
+    /// Samples the gradient at a position without computing every stop, for when the gradient is sampled
+    /// for every pixel. If every stop is a constant color, the computed gradient is kept between calls.
+    pub fn sample(&mut self, context: &ExpressionContext, position: f64) -> PixelColor {
+        if let Some(computed) = &self.computed {
+            return computed.sample(position);
+        }
+        if self.stops.iter().all(|stop| matches!(stop.color, AnyColorExpression::Constant(_))) {
+            let computed = self.compute(context);
+            return self.computed.insert(computed).sample(position);
+        }
+
+        // Stops are sorted in place so only the stops around the position need to be computed
+        if !self.stops.is_sorted_by(|a, b| a.position.total_cmp(&b.position).is_le()) {
+            self.stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        }
+        let Some((start, end, t)) = find_segment(|i| self.stops[i].position, self.stops.len(), position, self.wrap) else {
+            return PixelColor::BLACK;
+        };
+        let start_color = self.stops[start].color.compute(context);
+        if start == end {
+            return start_color;
+        }
+        let end_color = self.stops[end].color.compute(context);
+        interpolate(&start_color, &end_color, t, self.interpolation)
+    }
+
+    /// The colors of the stops. The computed gradient is recomputed on the next sample, since they can be changed.
+    pub fn stop_colors_mut(&mut self) -> impl Iterator<Item = &mut AnyColorExpression> {
+        self.computed = None;
+        self.stops.iter_mut().map(|stop| &mut stop.color)
+    }
+
+    pub fn fold_constants(&mut self) {
+        for color in self.stop_colors_mut() {
+            color.fold_constants();
+        }
+    }
+}
+
+/// Finds the stops a position falls between, given the number of stops and a function that gets the position of
+/// each stop in sorted order. Returns the indices of the stops and how far the position is from the first to the second.
+fn find_segment(stop_position: impl Fn(usize) -> f64, count: usize, position: f64, wrap: bool) -> Option<(usize, usize, f64)> {
+    let last = count.checked_sub(1)?;
+
+    let position = if wrap { position.rem_euclid(1.) } else { position };
+    // The first stop after the position
+    let next = (0..count).find(|i| stop_position(*i) > position).unwrap_or(count);
+    let ((start, start_position), (end, end_position)) = match (next, wrap) {
+        (0, false) => return Some((0, 0, 0.)),
+        (next, false) if next == count => return Some((last, last, 0.)),
+        // Blend across the end of the gradient from the last stop to the first
+        (0, true) => ((last, stop_position(last) - 1.), (0, stop_position(0))),
+        (next, true) if next == count => ((last, stop_position(last)), (0, stop_position(0) + 1.)),
+        (next, _) => ((next - 1, stop_position(next - 1)), (next, stop_position(next)))
+    };
+
+    let t = if end_position > start_position { (position - start_position) / (end_position - start_position) } else { 0. };
+    Some((start, end, t))
+}
+
+/// A gradient with the colors of its stops computed, sorted by position.
+#[derive(Clone, Debug)]
+pub struct ComputedGradient {
+    stops: Vec<(f64, PixelColor)>,
+    interpolation: GradientInterpolation,
+    wrap: bool
+}
+
+impl ComputedGradient {
+    pub fn sample(&self, position: f64) -> PixelColor {
+        let Some((start, end, t)) = find_segment(|i| self.stops[i].0, self.stops.len(), position, self.wrap) else {
+            return PixelColor::BLACK;
+        };
+        if start == end {
+            return self.stops[start].1.clone();
+        }
+        interpolate(&self.stops[start].1, &self.stops[end].1, t, self.interpolation)
+    }
+
+    /// Samples one of `count` colors evenly spaced along the gradient. For gradients that don't wrap, the first
+    /// and last colors are the ends of the gradient, so a gradient of evenly spaced stops gives back the stops.
+    pub fn sample_evenly(&self, index: usize, count: usize) -> PixelColor {
+        let spacing = if self.wrap { count } else { count.saturating_sub(1) }.max(1);
+        self.sample(index as f64 / spacing as f64)
+    }
+}
+
+fn interpolate(start: &PixelColor, end: &PixelColor, t: f64, interpolation: GradientInterpolation) -> PixelColor {
+    let alpha = start.alpha + (end.alpha - start.alpha) * t;
+    match interpolation {
+        GradientInterpolation::RGB => start.lerp(end, t),
+        GradientInterpolation::HSL => {
+            let (start_hue, start_saturation, start_lightness) = start.clone().to_hsl();
+            let (end_hue, end_saturation, end_lightness) = end.clone().to_hsl();
+            // Go around the color wheel the short way
+            let hue_difference = (end_hue - start_hue + 180.).rem_euclid(360.) - 180.;
+            PixelColor::from_hsl(
+                (start_hue + hue_difference * t).rem_euclid(360.),
+                start_saturation + (end_saturation - start_saturation) * t,
+                start_lightness + (end_lightness - start_lightness) * t,
+                alpha
+            )
+        }
+        GradientInterpolation::OKLab => {
+            let start = to_oklab(start);
+            let end = to_oklab(end);
+            from_oklab([0, 1, 2].map(|i| start[i] + (end[i] - start[i]) * t), alpha)
+        }
+    }
+}
+
+// The conversions to and from OKLab are from https://bottosson.github.io/posts/oklab/
+
+fn to_linear(channel: u8) -> f64 {
+    let channel = channel as f64 / 255.;
+    if channel <= 0.04045 { channel / 12.92 } else { ((channel + 0.055) / 1.055).powf(2.4) }
+}
+
+fn from_linear(channel: f64) -> u8 {
+    let channel = if channel <= 0.0031308 { channel * 12.92 } else { 1.055 * channel.powf(1. / 2.4) - 0.055 };
+    (channel.clamp(0., 1.) * 255.).round() as u8
+}
+
+fn to_oklab(color: &PixelColor) -> [f64; 3] {
+    let (r, g, b) = (to_linear(color.r), to_linear(color.g), to_linear(color.b));
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s
+    ]
+}
+
+fn from_oklab([lightness, a, b]: [f64; 3], alpha: f64) -> PixelColor {
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    PixelColor::new(
+        from_linear(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+        from_linear(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+        from_linear(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+        alpha
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::effects::RenderContext;
+    use super::*;
+
+    const RED: (u8, u8, u8) = (200, 0, 0);
+    const BLUE: (u8, u8, u8) = (0, 0, 200);
+    const PURPLE: (u8, u8, u8) = (100, 0, 100);
+
+    /// Builds a gradient with constant stops, or stops computed from expressions, which are sampled differently.
+    fn gradient(stops: &[(f64, (u8, u8, u8))], wrap: bool, constant: bool) -> Gradient {
+        let stops = stops.iter().map(|&(position, (r, g, b))| ColorStop {
+            position,
+            color: if constant {
+                PixelColor::new(r, g, b, 1.).into()
+            } else {
+                let channel = |value: u8| serde_json::json!({ "type": "Number", "value": value as f64 / 255. });
+                let color = serde_json::json!({ "type": "RGB", "red": channel(r), "green": channel(g), "blue": channel(b), "alpha": null });
+                <AnyColorExpression as Deserialize>::deserialize(color).unwrap()
+            }
+        }).collect();
+        Gradient { stops, interpolation: GradientInterpolation::RGB, wrap, computed: None }
+    }
+
+    /// Samples a gradient at every position, both directly and through its computed form.
+    fn sample(stops: &[(f64, (u8, u8, u8))], wrap: bool, positions: &[f64]) -> Vec<(u8, u8, u8)> {
+        let info = crate::RenderInfo::new(&crate::spatial_map());
+        let context = RenderContext { delta: std::time::Duration::ZERO, time: 0., pixels: 1 }.expression_context(&info);
+        let rgb = |color: PixelColor| (color.r, color.g, color.b);
+
+        let mut constant = gradient(stops, wrap, true);
+        let computed = constant.compute(&context);
+        let samples = positions.iter().map(|&position| rgb(constant.sample(&context, position))).collect::<Vec<_>>();
+        assert_eq!(positions.iter().map(|&position| rgb(computed.sample(position))).collect::<Vec<_>>(), samples);
+
+        let mut computed_per_sample = gradient(stops, wrap, false);
+        assert_eq!(positions.iter().map(|&position| rgb(computed_per_sample.sample(&context, position))).collect::<Vec<_>>(), samples);
+        samples
+    }
+
+    #[test]
+    fn clamps_positions_without_wrap() {
+        assert_eq!(
+            sample(&[(0.25, RED), (0.75, BLUE)], false, &[-1., 0., 0.25, 0.5, 0.75, 1., 2.]),
+            vec![RED, RED, RED, PURPLE, BLUE, BLUE, BLUE]
+        );
+    }
+
+    #[test]
+    fn blends_the_last_stop_into_the_first_with_wrap() {
+        assert_eq!(
+            sample(&[(0.25, RED), (0.75, BLUE)], true, &[0., 0.25, 0.5, 0.875, 1., 1.125, -0.125]),
+            vec![PURPLE, RED, PURPLE, (50, 0, 150), PURPLE, (150, 0, 50), (50, 0, 150)]
+        );
+    }
+
+    #[test]
+    fn sorts_stops() {
+        let positions = [0., 0.5, 0.875, 1.];
+        assert_eq!(
+            sample(&[(0.75, BLUE), (0.25, RED)], false, &positions),
+            sample(&[(0.25, RED), (0.75, BLUE)], false, &positions)
+        );
+        assert_eq!(
+            sample(&[(0.75, BLUE), (0.25, RED)], true, &positions),
+            sample(&[(0.25, RED), (0.75, BLUE)], true, &positions)
+        );
+    }
+
+    #[test]
+    fn single_stops_are_a_solid_color() {
+        for wrap in [false, true] {
+            assert_eq!(sample(&[(0.5, RED)], wrap, &[-1., 0., 0.5, 0.9, 2.]), vec![RED; 5]);
+        }
+    }
+
+    #[test]
+    fn samples_evenly_spaced_colors_as_the_original_stops() {
+        let colors = [(255, 0, 0), (12, 200, 34), (0, 0, 255), (90, 91, 92), (7, 255, 128)];
+        let json = colors.iter()
+            .map(|(r, g, b)| format!(r#"{{ "r": {}, "g": {}, "b": {}, "alpha": 1 }}"#, r, g, b))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut gradient: Gradient = serde_json::from_str(&format!("[{}]", json)).unwrap();
+        assert_eq!(gradient.interpolation, GradientInterpolation::RGB);
+        assert!(!gradient.wrap);
+
+        let info = crate::RenderInfo::new(&crate::spatial_map());
+        let context = RenderContext { delta: std::time::Duration::ZERO, time: 0., pixels: 1 }.expression_context(&info);
+        let computed = gradient.compute(&context);
+        let samples = (0..colors.len())
+            .map(|i| computed.sample_evenly(i, colors.len()))
+            .map(|color| (color.r, color.g, color.b))
+            .collect::<Vec<_>>();
+        assert_eq!(samples, colors);
+    }
+}
@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use super::frame::PixelColor;
+
+mod gif;
+
+static IMAGE_DIRECTORY: &str = "images";
+
+/// A decoded image. Still images have a single frame.
+#[derive(Debug)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub frames: Vec<ImageFrame>
+}
+
+#[derive(Debug)]
+pub struct ImageFrame {
+    /// The RGBA colors of the frame, row by row from the top left.
+    pub pixels: Vec<[u8; 4]>,
+    /// How long the frame is shown for, in seconds.
+    pub duration: f64
+}
+
+impl Image {
+    /// Decodes an image. Only GIFs are supported.
+    pub fn decode(data: &[u8]) -> Result<Image, String> {
+        gif::decode(data)
+    }
+
+    /// Loads an image that was saved to the controller.
+    pub fn load(name: &str) -> Result<Image, String> {
+        let data = std::fs::read(Image::get_path(name)?).map_err(|e| format!("Failed to read image: {}", e))?;
+        Image::decode(&data)
+    }
+
+    /// Saves an image to the controller so effects can use it.
+    /// The image is decoded first, so invalid images are never saved.
+    pub fn save(name: &str, data: &[u8]) -> Result<(), String> {
+        let path = Image::get_path(name)?;
+        Image::decode(data)?;
+        std::fs::create_dir_all(Image::get_directory()).map_err(|e| format!("Failed to create image directory: {}", e))?;
+        std::fs::write(path, data).map_err(|e| format!("Failed to save image: {}", e))
+    }
+
+    /// Lists the names of the images saved to the controller.
+    pub fn list() -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(Image::get_directory()) else {
+            return vec![];
+        };
+        let mut names = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    /// Gets a pixel of a frame, or a transparent pixel if it's out of bounds.
+    pub fn get_pixel(&self, frame: usize, x: usize, y: usize) -> PixelColor {
+        if x >= self.width || y >= self.height {
+            return PixelColor::new(0, 0, 0, 0.);
+        }
+        self.frames.get(frame)
+            .and_then(|frame| frame.pixels.get(y * self.width + x))
+            .map(|&[r, g, b, alpha]| PixelColor::new(r, g, b, alpha as f64 / 255.))
+            .unwrap_or(PixelColor::new(0, 0, 0, 0.))
+    }
+
+    /// Gets the frame shown after the image has played for `time` seconds.
+    /// If the image doesn't loop, it stays on the last frame.
+    pub fn frame_at(&self, time: f64, looping: bool) -> usize {
+        let total_duration: f64 = self.frames.iter().map(|frame| frame.duration).sum();
+        if total_duration <= 0. {
+            return 0;
+        }
+
+        let mut time = if looping { time.rem_euclid(total_duration) } else { time };
+        for (i, frame) in self.frames.iter().enumerate() {
+            if time < frame.duration {
+                return i;
+            }
+            time -= frame.duration;
+        }
+        self.frames.len() - 1
+    }
+
+    fn get_directory() -> PathBuf {
+        dirs::data_dir().unwrap().join(IMAGE_DIRECTORY)
+    }
+
+    fn get_path(name: &str) -> Result<PathBuf, String> {
+        // Don't allow names that could escape the image directory
+        let valid = !name.is_empty() && !name.starts_with('.') &&
+            name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+        if !valid {
+            return Err("Invalid image name".to_string());
+        }
+        Ok(Image::get_directory().join(name))
+    }
+}
@@ -0,0 +1,333 @@
+use super::{Image, ImageFrame};
+
+/// Frames without a delay are shown for this long, in seconds, like most browsers do.
+const DEFAULT_FRAME_DURATION: f64 = 0.1;
+
+/// The largest code the LZW compression in GIFs can use.
+const MAX_CODES: usize = 4096;
+
+/// The largest width and height of an image or frame. Uploads are decoded as they're saved,
+/// so this keeps a small file that claims to be huge from using up all the memory.
+const MAX_SIZE: usize = 1024;
+
+/// The most pixels all the frames of an image can have together, so animations stay around 64 MB at most.
+const MAX_TOTAL_PIXELS: usize = 16 * 1024 * 1024;
+
+const TRANSPARENT: [u8; 4] = [0, 0, 0, 0];
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize
+}
+
+impl<'a> Reader<'a> {
+    fn u8(&mut self) -> Result<u8, String> {
+        let byte = *self.data.get(self.position).ok_or("Unexpected end of GIF")?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(self.u8()? as u16 | (self.u8()? as u16) << 8)
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let bytes = self.data.get(self.position..self.position + length).ok_or("Unexpected end of GIF")?;
+        self.position += length;
+        Ok(bytes)
+    }
+
+    fn color_table(&mut self, packed: u8) -> Result<Vec<[u8; 4]>, String> {
+        let size = 2 << (packed & 0b111);
+        Ok(self.bytes(size * 3)?.chunks(3).map(|rgb| [rgb[0], rgb[1], rgb[2], 255]).collect())
+    }
+
+    /// Reads a series of data sub-blocks, ending with an empty one.
+    fn sub_blocks(&mut self) -> Result<Vec<u8>, String> {
+        let mut data = vec![];
+        loop {
+            let length = self.u8()? as usize;
+            if length == 0 {
+                return Ok(data);
+            }
+            data.extend_from_slice(self.bytes(length)?);
+        }
+    }
+}
+
+/// How a frame is cleared before the next one is drawn.
+#[derive(Clone, Copy, PartialEq)]
+enum Disposal {
+    Keep,
+    Clear,
+    Restore
+}
+
+/// Decodes a GIF. Frames are composited onto the full canvas as a browser would show them,
+/// so every frame of the result can be shown on its own.
+pub fn decode(data: &[u8]) -> Result<Image, String> {
+    let mut reader = Reader { data, position: 0 };
+    let signature = reader.bytes(6)?;
+    if signature != b"GIF87a" && signature != b"GIF89a" {
+        return Err("Not a GIF".to_string());
+    }
+
+    let width = reader.u16()? as usize;
+    let height = reader.u16()? as usize;
+    if width == 0 || height == 0 {
+        return Err("GIF has no pixels".to_string());
+    }
+    if width > MAX_SIZE || height > MAX_SIZE {
+        return Err(format!("GIF is larger than {}x{}", MAX_SIZE, MAX_SIZE));
+    }
+    let packed = reader.u8()?;
+    let _background = reader.u8()?;
+    let _aspect_ratio = reader.u8()?;
+    let global_color_table = if packed & 0x80 != 0 { Some(reader.color_table(packed)?) } else { None };
+
+    let mut canvas = vec![TRANSPARENT; width * height];
+    let mut frames = vec![];
+    // Set by the graphic control extension before each frame
+    let mut delay = 0;
+    let mut transparent_index = None;
+    let mut disposal = Disposal::Keep;
+
+    loop {
+        match reader.u8()? {
+            // Extension
+            0x21 => {
+                let label = reader.u8()?;
+                let data = reader.sub_blocks()?;
+                if label == 0xF9 && data.len() >= 4 {
+                    disposal = match (data[0] >> 2) & 0b111 {
+                        2 => Disposal::Clear,
+                        3 => Disposal::Restore,
+                        _ => Disposal::Keep
+                    };
+                    delay = data[1] as u16 | (data[2] as u16) << 8;
+                    transparent_index = if data[0] & 1 != 0 { Some(data[3]) } else { None };
+                }
+            }
+            // Image
+            0x2C => {
+                let left = reader.u16()? as usize;
+                let top = reader.u16()? as usize;
+                let frame_width = reader.u16()? as usize;
+                let frame_height = reader.u16()? as usize;
+                if frame_width > MAX_SIZE || frame_height > MAX_SIZE {
+                    return Err(format!("GIF frame is larger than {}x{}", MAX_SIZE, MAX_SIZE));
+                }
+                if (frames.len() + 1) * width * height > MAX_TOTAL_PIXELS {
+                    return Err("GIF has too many frames".to_string());
+                }
+                let packed = reader.u8()?;
+                let local_color_table = if packed & 0x80 != 0 { Some(reader.color_table(packed)?) } else { None };
+                let color_table = local_color_table.as_ref().or(global_color_table.as_ref()).ok_or("GIF frame has no color table")?;
+
+                let minimum_code_size = reader.u8()?;
+                let indices = decompress(&reader.sub_blocks()?, minimum_code_size, frame_width * frame_height)?;
+                let rows = if packed & 0x40 != 0 { interlaced_rows(frame_height) } else { (0..frame_height).collect() };
+
+                let previous = if disposal == Disposal::Restore { Some(canvas.clone()) } else { None };
+                for (row, y) in rows.into_iter().enumerate() {
+                    for x in 0..frame_width {
+                        // Frames with missing data are left partly transparent
+                        let Some(&index) = indices.get(row * frame_width + x) else {
+                            continue;
+                        };
+                        if Some(index) == transparent_index || left + x >= width || top + y >= height {
+                            continue;
+                        }
+                        if let Some(color) = color_table.get(index as usize) {
+                            canvas[(top + y) * width + left + x] = *color;
+                        }
+                    }
+                }
+
+                frames.push(ImageFrame {
+                    pixels: canvas.clone(),
+                    duration: if delay > 1 { delay as f64 / 100. } else { DEFAULT_FRAME_DURATION }
+                });
+
+                match disposal {
+                    Disposal::Keep => {}
+                    Disposal::Clear => for y in top..(top + frame_height).min(height) {
+                        for x in left..(left + frame_width).min(width) {
+                            canvas[y * width + x] = TRANSPARENT;
+                        }
+                    },
+                    Disposal::Restore => canvas = previous.unwrap_or(canvas)
+                }
+                delay = 0;
+                transparent_index = None;
+                disposal = Disposal::Keep;
+            }
+            // Trailer
+            0x3B => break,
+            block => return Err(format!("Unknown GIF block {:#04x}", block))
+        }
+    }
+
+    if frames.is_empty() {
+        return Err("GIF has no frames".to_string());
+    }
+    Ok(Image { width, height, frames })
+}
+
+/// The order rows of an interlaced frame are stored in.
+fn interlaced_rows(height: usize) -> Vec<usize> {
+    [(0, 8), (4, 8), (2, 4), (1, 2)].iter()
+        .flat_map(|&(start, step)| (start..height).step_by(step))
+        .collect()
+}
+
+/// Decompresses the LZW-compressed color indices of a frame, stopping once every pixel has an index.
+fn decompress(data: &[u8], minimum_code_size: u8, pixels: usize) -> Result<Vec<u8>, String> {
+    if !(1..=11).contains(&minimum_code_size) {
+        return Err("Invalid GIF code size".to_string());
+    }
+    let clear_code = 1usize << minimum_code_size;
+    let end_code = clear_code + 1;
+
+    // Every code is a previous code followed by one more byte
+    let mut prefixes = vec![0u16; MAX_CODES];
+    let mut suffixes = vec![0u8; MAX_CODES];
+    let mut lengths = vec![0usize; MAX_CODES];
+    for code in 0..clear_code {
+        suffixes[code] = code as u8;
+        lengths[code] = 1;
+    }
+
+    let mut output = Vec::with_capacity(pixels);
+    let mut code_size = minimum_code_size as u32 + 1;
+    let mut next_code = end_code + 1;
+    let mut previous: Option<usize> = None;
+    let mut bits = 0u32;
+    let mut bit_count = 0;
+    let mut bytes = data.iter();
+
+    loop {
+        // Anything after the last pixel isn't shown, and repeating long codes could otherwise use up all the memory
+        if output.len() >= pixels {
+            output.truncate(pixels);
+            return Ok(output);
+        }
+
+        while bit_count < code_size {
+            let Some(byte) = bytes.next() else {
+                // Some encoders leave out the end code
+                return Ok(output);
+            };
+            bits |= (*byte as u32) << bit_count;
+            bit_count += 8;
+        }
+        let code = (bits & ((1 << code_size) - 1)) as usize;
+        bits >>= code_size;
+        bit_count -= code_size;
+
+        if code == clear_code {
+            code_size = minimum_code_size as u32 + 1;
+            next_code = end_code + 1;
+            previous = None;
+            continue;
+        }
+        if code == end_code {
+            return Ok(output);
+        }
+
+        let Some(previous_code) = previous else {
+            if code >= clear_code {
+                return Err("Invalid GIF data".to_string());
+            }
+            output.push(code as u8);
+            previous = Some(code);
+            continue;
+        };
+        if code > next_code || (code == next_code && next_code >= MAX_CODES) {
+            return Err("Invalid GIF data".to_string());
+        }
+
+        // A code that isn't in the table yet is the previous code followed by its own first byte
+        let start = output.len();
+        let known = if code < next_code { code } else { previous_code };
+        output.resize(start + lengths[known], 0);
+        let mut current = known;
+        for i in (start..output.len()).rev() {
+            output[i] = suffixes[current];
+            current = prefixes[current] as usize;
+        }
+        let first_byte = output[start];
+        if code == next_code {
+            output.push(first_byte);
+        }
+
+        if next_code < MAX_CODES {
+            prefixes[next_code] = previous_code as u16;
+            suffixes[next_code] = first_byte;
+            lengths[next_code] = lengths[previous_code] + 1;
+            next_code += 1;
+            if next_code == 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+        }
+        previous = Some(code);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 2x2 GIF with a red, blue, blue, red frame shown for 0.1 seconds, then a frame shown for 0.2 seconds
+    /// that draws a transparent pixel over the top right and black over the bottom right.
+    const TWO_FRAMES: [u8; 73] = [
+        0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x02, 0x00, 0x02, 0x00, 0x81, 0x00, 0x00, 0xFF, 0x00, 0x00,
+        0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x21, 0xF9, 0x04, 0x00, 0x0A, 0x00, 0x00,
+        0x00, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x02, 0x03, 0x44, 0x02, 0x05,
+        0x00, 0x21, 0xF9, 0x04, 0x01, 0x14, 0x00, 0x03, 0x00, 0x2C, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x02, 0x00, 0x00, 0x02, 0x02, 0x9C, 0x0A, 0x00, 0x3B
+    ];
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+    #[test]
+    fn decodes_frames() {
+        let image = decode(&TWO_FRAMES).unwrap();
+        assert_eq!((image.width, image.height), (2, 2));
+        assert_eq!(image.frames.len(), 2);
+        assert_eq!(image.frames[0].pixels, vec![RED, BLUE, BLUE, RED]);
+        assert_eq!(image.frames[0].duration, 0.1);
+        assert_eq!(image.frames[1].pixels, vec![RED, BLUE, BLUE, BLACK]);
+        assert_eq!(image.frames[1].duration, 0.2);
+    }
+
+    #[test]
+    fn rejects_truncated_gifs() {
+        for length in 0..TWO_FRAMES.len() {
+            assert!(decode(&TWO_FRAMES[..length]).is_err(), "decoded a GIF cut off after {} bytes", length);
+        }
+    }
+
+    #[test]
+    fn rejects_oversized_gifs() {
+        let mut data = TWO_FRAMES;
+        data[6..10].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        assert!(decode(&data).is_err());
+
+        // A frame that claims to be huge inside a small canvas
+        let mut data = TWO_FRAMES;
+        data[62..66].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        assert!(decode(&data).is_err());
+    }
+
+    #[test]
+    fn stops_decompressing_after_the_last_pixel() {
+        // 100 zeros with a code size of 2
+        let data = [0x84, 0x8F, 0xA9, 0xCB, 0xED, 0x0F, 0x63, 0x2B];
+        assert_eq!(decompress(&data, 2, 100).unwrap(), vec![0; 100]);
+        assert_eq!(decompress(&data, 2, 10).unwrap(), vec![0; 10]);
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The current time of day in the controller's time zone.
+#[derive(Clone, Copy, Debug)]
+pub struct LocalTime {
+    /// The seconds since midnight, including fractions of a second.
+    pub seconds: f64,
+    /// The day of the week, from 0 on Sunday to 6 on Saturday.
+    pub weekday: u32
+}
+
+impl LocalTime {
+    pub fn now() -> LocalTime {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let timestamp = now.as_secs() as libc::time_t;
+
+        // SAFETY: localtime_r only writes to the tm we pass it, and it's thread-safe unlike localtime.
+        let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+        if unsafe { libc::localtime_r(&timestamp, &mut tm) }.is_null() {
+            // Fall back to UTC
+            return LocalTime {
+                seconds: (now.as_secs() % 86400) as f64 + now.subsec_nanos() as f64 / 1e9,
+                // January 1, 1970 was a Thursday
+                weekday: ((now.as_secs() / 86400 + 4) % 7) as u32
+            };
+        }
+
+        LocalTime {
+            seconds: (tm.tm_hour * 3600 + tm.tm_min * 60 + tm.tm_sec) as f64 + now.subsec_nanos() as f64 / 1e9,
+            weekday: tm.tm_wday as u32
+        }
+    }
+
+    pub fn hours(&self) -> f64 {
+        self.seconds / 3600.
+    }
+
+    /// The seconds since the start of the week, at midnight on Sunday.
+    pub fn seconds_in_week(&self) -> f64 {
+        self.weekday as f64 * 86400. + self.seconds
+    }
+}
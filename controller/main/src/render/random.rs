@@ -0,0 +1,107 @@
+/// A small, fast pseudorandom number generator (SplitMix64).
+/// We use our own generator so that sequences are identical across platforms and restarts
+/// when given the same seed.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> SeededRng {
+        SeededRng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        mix(self.state)
+    }
+
+    /// Returns a random number in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Scrambles the bits of a value. Used to turn seeds and lattice coordinates into random values.
+fn mix(value: u64) -> u64 {
+    let mut z = value;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Hashes a seed and an integer coordinate to a random number in [0, 1).
+pub fn hash_to_unit(seed: u64, x: i64) -> f64 {
+    let hash = mix(seed ^ mix(x as u64));
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Smooth 1D value noise in [0, 1). The noise has a feature size of 1 unit of `x`.
+pub fn value_noise_1d(seed: u64, x: f64) -> f64 {
+    let cell = x.floor();
+    // Smoothstep between the random values at the neighboring lattice points
+    let t = smoothstep(x - cell);
+    let a = hash_to_unit(seed, cell as i64);
+    let b = hash_to_unit(seed, cell as i64 + 1);
+    lerp(a, b, t)
+}
+
+/// Hashes a seed and a 2D integer coordinate to a random number in [0, 1).
+pub fn hash_to_unit_2d(seed: u64, x: i64, y: i64) -> f64 {
+    hash_to_unit(seed ^ mix(y as u64).rotate_left(17), x)
+}
+
+/// Smooth 2D value noise in [0, 1). The noise has a feature size of 1 unit.
+pub fn value_noise_2d(seed: u64, x: f64, y: f64) -> f64 {
+    let (cell_x, cell_y) = (x.floor(), y.floor());
+    let (tx, ty) = (smoothstep(x - cell_x), smoothstep(y - cell_y));
+    let (cx, cy) = (cell_x as i64, cell_y as i64);
+
+    let top = lerp(hash_to_unit_2d(seed, cx, cy), hash_to_unit_2d(seed, cx + 1, cy), tx);
+    let bottom = lerp(hash_to_unit_2d(seed, cx, cy + 1), hash_to_unit_2d(seed, cx + 1, cy + 1), tx);
+    lerp(top, bottom, ty)
+}
+
+/// 1D Perlin (gradient) noise in [0, 1]. Compared to value noise, it has fewer visible
+/// regularities, and it's always 0.5 at whole numbers.
+pub fn perlin_noise_1d(seed: u64, x: f64) -> f64 {
+    let cell = x.floor();
+    let t = x - cell;
+    let gradient = |offset: i64| hash_to_unit(seed, cell as i64 + offset) * 2. - 1.;
+
+    let noise = lerp(gradient(0) * t, gradient(1) * (t - 1.), fade(t));
+    // Gradients are at most 1 in magnitude, so the noise is within [-0.5, 0.5]
+    noise + 0.5
+}
+
+/// 2D Perlin (gradient) noise in [0, 1]. The noise has a feature size of 1 unit.
+pub fn perlin_noise_2d(seed: u64, x: f64, y: f64) -> f64 {
+    let (cell_x, cell_y) = (x.floor(), y.floor());
+    let (tx, ty) = (x - cell_x, y - cell_y);
+    let (cx, cy) = (cell_x as i64, cell_y as i64);
+
+    // Each lattice point gets a random unit gradient
+    let dot = |ox: i64, oy: i64| {
+        let angle = hash_to_unit_2d(seed, cx + ox, cy + oy) * std::f64::consts::TAU;
+        angle.cos() * (tx - ox as f64) + angle.sin() * (ty - oy as f64)
+    };
+
+    let top = lerp(dot(0, 0), dot(1, 0), fade(tx));
+    let bottom = lerp(dot(0, 1), dot(1, 1), fade(tx));
+    let noise = lerp(top, bottom, fade(ty));
+    // With unit gradients, 2D Perlin noise is within [-sqrt(2)/2, sqrt(2)/2]
+    noise * std::f64::consts::FRAC_1_SQRT_2 + 0.5
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+fn smoothstep(t: f64) -> f64 {
+    t * t * (3. - 2. * t)
+}
+
+/// Perlin's quintic fade curve, which has continuous first and second derivatives.
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
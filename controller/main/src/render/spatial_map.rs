@@ -1,5 +1,8 @@
+use reflection::Reflect;
+use serde::{Deserialize, Serialize};
+
 /// A 2D location, with x and y coordinates in meters.
-#[derive(Debug, Clone)]
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone)]
 pub struct Location {
     pub x: f32,
     pub y: f32,
@@ -28,6 +31,17 @@ impl Location {
     }
 }
 
+/// Gets the corners of the smallest rectangle that contains every location, as the minimum and maximum corners.
+pub fn get_bounds<'a>(locations: impl IntoIterator<Item = &'a Location>) -> (Location, Location) {
+    locations.into_iter().fold(
+        (Location::new(f32::INFINITY, f32::INFINITY), Location::new(f32::NEG_INFINITY, f32::NEG_INFINITY)),
+        |(min, max), location| (
+            Location::new(min.x.min(location.x), min.y.min(location.y)),
+            Location::new(max.x.max(location.x), max.y.max(location.y))
+        )
+    )
+}
+
 /// A span of pixel locations between two corners.
 /// The start index is inclusive, while the end index is exclusive.
 #[derive(Debug, Clone)]
@@ -68,6 +82,10 @@ impl PixelSpan {
 pub struct SpatialMap {
     pixels: u32,
     spans: Vec<PixelSpan>,
+    /// The start and end index of every span as it was added, before spans that wrap around are split.
+    segments: Vec<(i32, i32)>,
+    /// Named points in the room, like the door, that effects can be triggered from.
+    named_locations: Vec<(String, Location)>
 }
 
 impl SpatialMap {
@@ -75,6 +93,8 @@ impl SpatialMap {
         SpatialMap {
             pixels,
             spans: vec![],
+            segments: vec![],
+            named_locations: vec![]
         }
     }
 
@@ -83,6 +103,7 @@ impl SpatialMap {
         if end_index < 0 {
             panic!("End index cannot be negative");
         }
+        self.segments.push((start_index, end_index));
 
         if start_index < 0 {
             let ratio = (-start_index) as f32 / (end_index - start_index) as f32;
@@ -97,6 +118,16 @@ impl SpatialMap {
         self
     }
 
+    /// Names a point in the room so effects can be triggered from it by name.
+    pub fn add_named_location(&mut self, name: &str, location: Location) -> &mut Self {
+        self.named_locations.push((name.to_string(), location));
+        self
+    }
+
+    pub fn get_named_locations(&self) -> Vec<(String, Location)> {
+        self.named_locations.clone()
+    }
+
     /// Gets the location of a pixel by linearly interpolating
     pub fn get_pixel_location(&self, index: u32) -> Location {
         for span in &self.spans {
@@ -119,4 +150,12 @@ impl SpatialMap {
 
         locations
     }
+
+    /// Gets the pixels of every span, in order from the start of the span to its end.
+    /// Spans that wrap around the start of the strip are kept together, so every segment is a continuous line in the room.
+    pub fn get_segments(&self) -> Vec<Vec<u32>> {
+        self.segments.iter().map(|(start, end)| {
+            (*start..*end).map(|index| index.rem_euclid(self.pixels as i32) as u32).collect()
+        }).collect()
+    }
 }
\ No newline at end of file
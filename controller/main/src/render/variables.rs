@@ -0,0 +1,52 @@
+use std::{collections::HashMap, time::Duration};
+
+/// How long it takes a variable to get most of the way to a new value, in seconds.
+/// Changes are smoothed so moving a slider doesn't make animations jump.
+static SMOOTHING_TIME: f64 = 0.1;
+
+/// Named global values that can be changed while effects are running, like `speed` or `hue`.
+/// Expressions read them with `VariableExpression`.
+#[derive(Debug, Default)]
+pub struct Variables {
+    values: HashMap<String, Variable>
+}
+
+#[derive(Debug)]
+struct Variable {
+    /// The smoothed value expressions currently see.
+    value: f64,
+    /// The value that was last set.
+    target: f64
+}
+
+impl Variables {
+    /// Gets the current smoothed value of a variable.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.values.get(name).map(|variable| variable.value)
+    }
+
+    /// Sets a variable. Existing variables move smoothly to the new value, and new variables take it immediately.
+    pub fn set(&mut self, name: String, value: f64) {
+        self.values.entry(name)
+            .and_modify(|variable| variable.target = value)
+            .or_insert(Variable { value, target: value });
+    }
+
+    /// Removes a variable. Returns false if it didn't exist.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.values.remove(name).is_some()
+    }
+
+    /// Lists every variable with the value it was last set to.
+    pub fn list(&self) -> HashMap<String, f64> {
+        self.values.iter().map(|(name, variable)| (name.clone(), variable.target)).collect()
+    }
+
+    /// Moves every variable toward its target. Called once per frame.
+    pub fn update(&mut self, delta: Duration) {
+        let t = 1. - (-delta.as_secs_f64() / SMOOTHING_TIME).exp();
+        for variable in self.values.values_mut() {
+            variable.value += (variable.target - variable.value) * t;
+        }
+    }
+}
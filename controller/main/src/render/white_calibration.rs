@@ -0,0 +1,81 @@
+use std::{io::Error, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::frame::PixelColor;
+
+static WHITE_CALIBRATION_FILE: &str = "white_calibration.json";
+
+/// How much to scale each channel of a white at a color temperature.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WhiteCalibrationPoint {
+    /// The color temperature, in Kelvin.
+    pub temperature: f64,
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64
+}
+
+/// Corrects whites for the LEDs in this installation. LEDs have a different white point than light bulbs,
+/// so a white computed from a color temperature doesn't look like a bulb of that temperature without correction.
+/// To calibrate, put a white effect next to a bulb of a known temperature and adjust the channel scales until they match.
+/// Scales are interpolated between calibrated temperatures.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct WhiteCalibration {
+    points: Vec<WhiteCalibrationPoint>
+}
+
+impl WhiteCalibration {
+    pub fn load() -> Self {
+        if let Ok(file) = std::fs::File::open(WhiteCalibration::get_file_path()) {
+            match serde_json::from_reader(file) {
+                Ok(calibration) => return calibration,
+                Err(e) => println!("Failed to load the white calibration from file: {}; using no calibration", e)
+            }
+        }
+        WhiteCalibration::default()
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let file = std::fs::File::create(WhiteCalibration::get_file_path())?;
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    /// Gets the calibrated white at a color temperature in Kelvin, with a brightness from 0 to 1.
+    pub fn white(&self, temperature: f64, brightness: f64) -> PixelColor {
+        let color = PixelColor::from_kelvin(temperature, 1.);
+        let (red, green, blue) = self.get_scales(temperature);
+        let brightness = brightness.clamp(0., 1.);
+        PixelColor::new(
+            (color.r as f64 * (red * brightness).clamp(0., 1.)).round() as u8,
+            (color.g as f64 * (green * brightness).clamp(0., 1.)).round() as u8,
+            (color.b as f64 * (blue * brightness).clamp(0., 1.)).round() as u8,
+            1.
+        )
+    }
+
+    fn get_scales(&self, temperature: f64) -> (f64, f64, f64) {
+        let mut points = self.points.iter().collect::<Vec<_>>();
+        points.sort_by(|a, b| a.temperature.total_cmp(&b.temperature));
+
+        let scales = |point: &WhiteCalibrationPoint| (point.red, point.green, point.blue);
+        let next = points.partition_point(|point| point.temperature <= temperature);
+        match (next.checked_sub(1).map(|i| points[i]), points.get(next).copied()) {
+            (None, None) => (1., 1., 1.),
+            (Some(point), None) | (None, Some(point)) => scales(point),
+            (Some(start), Some(end)) => {
+                let t = (temperature - start.temperature) / (end.temperature - start.temperature);
+                (
+                    start.red + (end.red - start.red) * t,
+                    start.green + (end.green - start.green) * t,
+                    start.blue + (end.blue - start.blue) * t
+                )
+            }
+        }
+    }
+
+    fn get_file_path() -> PathBuf {
+        dirs::data_dir().unwrap().join(WHITE_CALIBRATION_FILE)
+    }
+}
@@ -3,6 +3,8 @@ use config::Configuration;
 
 mod music_visualizer;
 mod temporary_effect;
+mod run_effect;
+mod variable;
 mod config;
 
 // TODO: Retrieve from server?
@@ -18,6 +20,9 @@ fn main() {
             clap::command!("music-visualizer"),
             clap::command!("temporary-effect")
                 .arg(arg!(<EFFECT> "The effect to run")),
+            clap::command!("run-effect")
+                .arg(arg!(<FILE> "A JSON file containing the effect to run")),
+            variable::command(),
             config::command()
         ]);
     
@@ -32,6 +37,11 @@ fn main() {
             address,
             matches.get_one::<String>("effect").expect("Effect is required")
         ),
+        Some(("run-effect", matches)) => run_effect::run(
+            address,
+            std::path::Path::new(matches.get_one::<String>("FILE").expect("File is required"))
+        ),
+        Some(("variable", matches)) => variable::run(address, matches),
         Some(("config", matches)) => config::run(matches),
         _ => unreachable!()
     };
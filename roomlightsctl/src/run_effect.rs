@@ -0,0 +1,20 @@
+use std::{net::IpAddr, path::Path};
+
+use shared::constants::API_PORT;
+
+/// Runs an effect from a JSON file. Expressions in the file can be written as text, like `"time * 84"`.
+pub fn run(address: IpAddr, path: &Path) {
+    let effect = std::fs::read_to_string(path).expect("Failed to read effect file");
+
+    let socket_address: std::net::SocketAddr = (address, API_PORT).into();
+    let response = reqwest::blocking::Client::new()
+        .post(format!("http://{}/api/run_effect", socket_address))
+        .header("Content-Type", "application/json")
+        .body(effect)
+        .send()
+        .expect("Failed to send request to server");
+
+    if !response.status().is_success() {
+        eprintln!("Failed to run effect: {}", response.text().unwrap_or_default());
+    }
+}
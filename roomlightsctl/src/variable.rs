@@ -0,0 +1,63 @@
+use std::net::IpAddr;
+
+use shared::{constants::API_PORT, VariableList};
+
+pub(crate) fn command() -> clap::Command {
+    clap::command!("variable")
+        .about("Lists and sets the variables expressions read with `$name`")
+        .subcommand_required(false)
+        .subcommands([
+            clap::command!("set")
+                .arg(clap::arg!(<NAME> "The name of the variable"))
+                .arg(clap::arg!(<VALUE> "The new value").allow_negative_numbers(true)),
+            clap::command!("remove")
+                .arg(clap::arg!(<NAME> "The name of the variable"))
+        ])
+}
+
+pub(crate) fn run(address: IpAddr, matches: &clap::ArgMatches) {
+    let socket_address: std::net::SocketAddr = (address, API_PORT).into();
+    let client = reqwest::blocking::Client::new();
+
+    let response = match matches.subcommand() {
+        Some(("set", matches)) => {
+            let name = matches.get_one::<String>("NAME").expect("Name is required");
+            let value = matches.get_one::<String>("VALUE").expect("Value is required")
+                .parse::<f64>()
+                .expect("Failed to parse value");
+
+            client.put(format!("http://{}/api/variable/{}", socket_address, name))
+                .header("Content-Type", "application/json")
+                .body(value.to_string())
+                .send()
+                .expect("Failed to send request to server")
+        },
+        Some(("remove", matches)) => {
+            let name = matches.get_one::<String>("NAME").expect("Name is required");
+            client.delete(format!("http://{}/api/variable/{}", socket_address, name))
+                .send()
+                .expect("Failed to send request to server")
+        },
+        None => {
+            let response = client.get(format!("http://{}/api/variables", socket_address))
+                .send()
+                .expect("Failed to send request to server");
+            let list: VariableList = serde_json::from_str(&response.text().expect("Failed to read response"))
+                .expect("Failed to parse variable list");
+
+            if list.variables.is_empty() {
+                println!("No variables are set");
+            }
+            for variable in list.variables {
+                println!("{} = {}", variable.name, variable.value);
+            }
+            return;
+        }
+        _ => unreachable!()
+    };
+
+    let text = response.text().unwrap_or_default();
+    if text.contains("\"Error\"") {
+        eprintln!("Failed to update variable: {}", text);
+    }
+}
@@ -1,6 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type ClientToServerMessage = never;
+export type ClientToServerMessage = { "type": "SetVariable" } & Variable;
 
 export type EffectPreset = { id: string, name: string, icon: string, };
 
@@ -12,6 +12,10 @@ export type LightPosition = { x: number, y: number, };
 
 export type MusicVisualizerMessage = { "type": "UpdateSpectrum" } & Array<number>;
 
+export type NodeGroup = { id: string, name: string, };
+
+export type NodeGroupList = { groups: Array<NodeGroup>, };
+
 export type ServerToClientMessage = { "type": "StatusUpdate" } & StatusUpdateMessage | { "type": "SystemStatusUpdate" } & SystemStatusUpdateMessage | { "type": "Initialize" } & InitializeMessage;
 
 export type StatusUpdateMessage = { 
@@ -49,3 +53,10 @@ export type SystemStatusUpdateMessage = { global_cpu: number, available_memory:
 export type TemporaryEffect = { id: string, name: string, };
 
 export type TemporaryEffectList = { effects: Array<TemporaryEffect>, };
+
+/**
+ * A named value that expressions can read with `$name`, used to adjust running effects.
+ */
+export type Variable = { name: string, value: number, };
+
+export type VariableList = { variables: Array<Variable>, };
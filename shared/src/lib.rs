@@ -27,6 +27,7 @@ pub enum ServerToClientMessage {
 pub enum ClientToServerMessage {
     // Pixel data updates use a binary message instead of JSON
     // PixelDataUpdate(Vec<u8>),
+    SetVariable(Variable),
 }
 
 #[derive(TS, Serialize, Deserialize)]
@@ -56,6 +57,33 @@ pub struct TemporaryEffect {
     pub name: String
 }
 
+#[derive(TS, Serialize, Deserialize)]
+#[ts(export, export_to = "index.ts")]
+pub struct NodeGroupList {
+    pub groups: Vec<NodeGroup>
+}
+
+#[derive(TS, Serialize, Deserialize)]
+#[ts(export, export_to = "index.ts")]
+pub struct NodeGroup {
+    pub id: String,
+    pub name: String
+}
+
+#[derive(TS, Serialize, Deserialize)]
+#[ts(export, export_to = "index.ts")]
+pub struct VariableList {
+    pub variables: Vec<Variable>
+}
+
+/// A named value that expressions can read with `$name`, used to adjust running effects.
+#[derive(TS, Serialize, Deserialize)]
+#[ts(export, export_to = "index.ts")]
+pub struct Variable {
+    pub name: String,
+    pub value: f64
+}
+
 #[derive(TS, Serialize, Deserialize)]
 #[ts(export, export_to = "index.ts")]
 pub struct InitializeMessage {